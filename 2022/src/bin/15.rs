@@ -1,11 +1,12 @@
 // Solving https://adventofcode.com/2022/day/15
-advent_of_code_2022::solution!(15);
+use advent_of_code_2022::template::runner::Context;
+
+advent_of_code_2022::solution!(15, {
+    ROW: example 10, real 2_000_000;
+    MAX_COORD: example 20, real 4_000_000;
+});
 
-const TEST_ROW: i32 = 10;
-const REAL_ROW: i32 = 2_000_000;
 const MIN_COORD: i32 = 0;
-const MIN_TEST_COORD: i32 = 20;
-const MIN_REAL_COORD: i32 = 4_000_000;
 const TUNING_CONST: u64 = 4_000_000;
 
 struct Sensor {
@@ -18,13 +19,12 @@ struct Beacon {
     y: i32,
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let is_real = input.len() > 1000;
-    let sensors = get_input(is_real);
+pub fn part_one(_input: &str, context: &Context) -> Option<i32> {
+    let sensors = get_input(!context.is_example());
     let (sensors, beacons) = input_to_sensor_ranges(sensors);
     let (min_out, max_out) = get_furthest_out(&sensors);
     let mut count = 0;
-    let row = get_row(is_real);
+    let row: i32 = context.param("ROW");
     for col_idx in min_out..max_out {
         if sensor_in_range(&sensors, row, col_idx) && not_beacon(&beacons, row, col_idx) {
             count += 1;
@@ -33,11 +33,10 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(count)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let is_real = input.len() > 1000;
-    let sensors = get_input(is_real);
+pub fn part_two(_input: &str, context: &Context) -> Option<u64> {
+    let sensors = get_input(!context.is_example());
     let (sensors, _) = input_to_sensor_ranges(sensors);
-    let max_coord = get_max_coord(is_real);
+    let max_coord: i32 = context.param("MAX_COORD");
     for row_idx in MIN_COORD..max_coord {
         match check_row(&sensors, max_coord, row_idx) {
             None => (),
@@ -91,14 +90,6 @@ fn check_row(sensors: &Vec<(Sensor, i32)>, max_coord: i32, row: i32) -> Option<i
     }
 }
 
-fn get_max_coord(is_real: bool) -> i32 {
-    if is_real {
-        MIN_REAL_COORD
-    } else {
-        MIN_TEST_COORD
-    }
-}
-
 fn sensor_in_range(sensors: &Vec<(Sensor, i32)>, row: i32, col: i32) -> bool {
     for (s, r) in sensors {
         let dist = (s.x - col).abs() + (s.y - row).abs();
@@ -118,14 +109,6 @@ fn not_beacon(beacons: &Vec<Beacon>, row: i32, col: i32) -> bool {
     true
 }
 
-fn get_row(is_real: bool) -> i32 {
-    if is_real {
-        REAL_ROW
-    } else {
-        TEST_ROW
-    }
-}
-
 fn get_furthest_out(sensors: &Vec<(Sensor, i32)>) -> (i32, i32) {
     let mut max = 0;
     let mut min = 100_000_000;
@@ -208,14 +191,14 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code_2022::template::read_file("examples", DAY);
-        let result = part_one(&input);
+        let result = part_one(&input, &Context::example(PARAMS));
         assert_eq!(result, Some(26));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code_2022::template::read_file("examples", DAY);
-        let result = part_two(&input);
+        let result = part_two(&input, &Context::example(PARAMS));
         assert_eq!(result, Some(56000011));
     }
 }
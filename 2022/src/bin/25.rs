@@ -1,5 +1,5 @@
 // Solving https://adventofcode.com/2022/day/25
-advent_of_code_2022::solution!(25);
+advent_of_code_2022::solution!(25, 1);
 
 use std::collections::HashMap;
 
@@ -12,10 +12,6 @@ pub fn part_one(input: &str) -> Option<String> {
     Some(vals_to_snafu(&place_vals))
 }
 
-pub fn part_two(_input: &str) -> Option<i32> {
-    None
-}
-
 fn vals_to_snafu(place_vals: &HashMap<u32, i8>) -> String {
     let mut chars = Vec::new();
     let mut places: Vec<u32> = place_vals.keys().map(|x| *x).collect();
@@ -1,3 +1,3 @@
-pub mod grid;
-pub mod parse;
-pub mod show;
+// The grid, graph and parsing utilities are shared by all years through `aoc_core`.
+// Add modules that only this year needs below.
+pub use aoc_core::utils::*;
@@ -0,0 +1,4 @@
+//! Generates the registry of the `solutions` binary and the example tests, see `aoc_core::template::registry`.
+fn main() {
+    aoc_core::template::registry::generate();
+}
@@ -198,35 +198,4 @@ fn prune_end_nodes(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_example_one() {
-        let input = advent_of_code_2024::template::read_file("examples", DAY);
-        let result = part_one(&input);
-        assert_eq!(result, Some(7036));
-    }
-
-    #[test]
-    fn test_part_one_example_two() {
-        let input = advent_of_code_2024::template::read_file_part("examples", DAY, 2);
-        let result = part_one(&input);
-        assert_eq!(result, Some(11048));
-    }
-
-    #[test]
-    fn test_part_two_example_one() {
-        let input = advent_of_code_2024::template::read_file("examples", DAY);
-        let result = part_two(&input);
-        assert_eq!(result, Some(45));
-    }
-
-    #[test]
-    fn test_part_two_example_two() {
-        let input = advent_of_code_2024::template::read_file_part("examples", DAY, 2);
-        let result = part_two(&input);
-        assert_eq!(result, Some(64));
-    }
-}
+advent_of_code_2024::solution_tests!();
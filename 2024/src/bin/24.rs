@@ -252,28 +252,4 @@ fn get_gates(input: &str) -> Vec<Gate> {
     gates
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_example_one() {
-        let input = advent_of_code_2024::template::read_file("examples", DAY);
-        let result = part_one(&input);
-        assert_eq!(result, Some(4));
-    }
-
-    #[test]
-    fn test_part_one_example_two() {
-        let input = advent_of_code_2024::template::read_file_part("examples", DAY, 2);
-        let result = part_one(&input);
-        assert_eq!(result, Some(2024));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code_2024::template::read_file("examples", DAY);
-        let result = part_two(&input);
-        assert_eq!(result, Some("1".to_string()));
-    }
-}
+advent_of_code_2024::solution_tests!();
@@ -1,7 +1,7 @@
 // Solving https://adventofcode.com/2024/day/25
 use advent_of_code_2024::utils::{grid::Grid, parse};
 
-advent_of_code_2024::solution!(25);
+advent_of_code_2024::solution!(25, 1);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (locks, keys) = parse_input(input);
@@ -24,10 +24,6 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(matches)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    None
-}
-
 fn parse_input(input: &str) -> (Vec<[i32; 5]>, Vec<[i32; 5]>) {
     let mut locks = vec![];
     let mut keys = vec![];
@@ -72,11 +68,4 @@ mod tests {
         let result = part_one(&input);
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code_2024::template::read_file("examples", DAY);
-        let result = part_two(&input);
-        assert_eq!(result, None);
-    }
 }
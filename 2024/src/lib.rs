@@ -1,4 +1,4 @@
-pub mod template;
+aoc_core::year_crate!();
 pub mod utils;
 
 // Use this file to add helper functions and additional modules.
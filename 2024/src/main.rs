@@ -1,15 +1,15 @@
 use advent_of_code_2024::template::commands::{
-    all, attempt, download, read, scaffold, set_year, solve, time,
+    all, attempt, download, examples, read, scaffold, set_year, solve, time, verify,
 };
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code_2024::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code_2024::template::Day;
+    use advent_of_code_2024::template::{runner::OutputFormat, Day};
     use std::process;
 
     pub enum AppArguments {
@@ -19,16 +19,21 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: OutputFormat,
         },
         Try {
             day: Day,
@@ -37,11 +42,22 @@ mod args {
         },
         All {
             release: bool,
+            jobs: Option<usize>,
+            timeout: Option<u64>,
+            format: OutputFormat,
+        },
+        Verify {
+            release: bool,
+            timeout: Option<u64>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            stats: bool,
+            threshold: Option<f64>,
+            timeout: Option<u64>,
+            format: OutputFormat,
         },
         NewYear,
         SetYear {
@@ -58,15 +74,30 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            },
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                timeout: args.opt_value_from_str("--timeout")?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let stats = args.contains("--stats");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let timeout = args.opt_value_from_str("--timeout")?;
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    stats,
+                    threshold,
+                    timeout,
+                    format,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -75,16 +106,21 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
             },
             Some("try") => AppArguments::Try {
                 day: args.free_from_str()?,
@@ -118,45 +154,68 @@ mod args {
 }
 
 fn main() {
+    // commands run from a year's directory always apply to that year.
+    advent_of_code_2024::template::set_year_from_package(env!("CARGO_PKG_NAME"));
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                jobs,
+                timeout,
+                format,
+            } => all::handle(release, jobs, timeout, format),
+            AppArguments::Verify { release, timeout } => verify::handle(release, timeout),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                stats,
+                threshold,
+                timeout,
+                format,
+            } => time::handle(day, all, store, stats, threshold, timeout, format),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
+            AppArguments::Examples { day } => examples::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
                 if download {
                     download::handle(day);
                 }
+                scaffold::handle(day, overwrite, template.as_deref());
+                if download {
+                    examples::handle(day);
+                }
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                format,
+            } => solve::handle(day, release, dhat, submit, format),
             AppArguments::Try { day, test, dhat } => attempt::handle(day, test, dhat),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
                         download::handle(day);
+                        scaffold::handle(day, false, None);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run on the days of \
+                            this december's event. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -165,12 +224,12 @@ fn main() {
             AppArguments::NewYear => {
                 println!("You can only generate new year folders at the project root");
             }
-            AppArguments::SetYear { year } => {
-                set_year::handle(year);
-                println!("Set repository to AOC year {}", year);
-            }
+            AppArguments::SetYear { year } => set_year::handle(year),
             AppArguments::GetYear => {
-                let year = advent_of_code_2024::template::get_year_exit_on_fail();
+                let Some(year) = set_year::configured_year() else {
+                    eprintln!("Failed to get the currently set AOC year");
+                    std::process::exit(1);
+                };
                 println!("The repository is currently set to {}", year);
             }
         },
@@ -1,6 +1,3 @@
-pub mod direction;
-pub mod grid;
-pub mod parse;
-pub mod show;
-pub mod graph_algos;
-pub mod grid_graph;
+// The grid, graph and parsing utilities are shared by all years through `aoc_core`.
+// Add modules that only this year needs below.
+pub use aoc_core::utils::*;
@@ -1,16 +1,13 @@
 
 use std::{collections::HashSet, fmt::Display};
 
-use advent_of_code_2025::utils::parse;
+use advent_of_code_2025::{template::runner::Context, utils::parse};
 
 // Solving https://adventofcode.com/2025/day/8
-advent_of_code_2025::solution!(8);
-
-const PART_ONE_TEST_ITERS: u64 = 10;
-const PART_ONE_REAL_ITERS: u64 = 1000;
+advent_of_code_2025::solution!(8, parse: parse_input, { PART_ONE_ITERS: example 10, real 1000 });
 
 #[derive(Hash, Eq, PartialEq, Debug)]
-struct Point {
+pub struct Point {
     x: i64,
     y: i64,
     z: i64,
@@ -32,12 +29,11 @@ impl Display for Point {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let boxes = parse_input(input);
-    let mut min_dists = get_min_dists(&boxes);
+pub fn part_one(boxes: &[Point], context: &Context) -> Option<u64> {
+    let mut min_dists = get_min_dists(boxes);
     let mut connected = HashSet::new();
     let mut circuits: Vec<HashSet<&Point>> = vec![];
-    for _ in 0..PART_ONE_TEST_ITERS {
+    for _ in 0..context.param::<u64>("PART_ONE_ITERS") {
         connect_boxes(&mut min_dists, &mut connected, &mut circuits);
     }
     let mut sizes = circuits.iter().map(|f| f.len()).collect::<Vec<usize>>();
@@ -45,9 +41,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some((sizes[0] * sizes[1] * sizes[2]) as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let boxes = parse_input(input);
-    let mut min_dists = get_min_dists(&boxes);
+pub fn part_two(boxes: &[Point]) -> Option<u64> {
+    let mut min_dists = get_min_dists(boxes);
     let mut connected = HashSet::new();
     let mut circuits = vec![];
     loop {
@@ -118,14 +113,14 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code_2025::template::read_file("examples", DAY);
-        let result = part_one(&input);
+        let result = part_one(&parse_input(&input), &Context::example(PARAMS));
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code_2025::template::read_file("examples", DAY);
-        let result = part_two(&input);
+        let result = part_two(&parse_input(&input));
         assert_eq!(result, Some(25272));
     }
 }
@@ -0,0 +1,58 @@
+//! Runs every solution of the year in-process.
+//! The `all`, `time` and `verify` commands invoke this binary once instead of spawning one process per day.
+use std::process;
+use std::time::Duration;
+
+use advent_of_code_2025::template::commands::{all, time, verify};
+use advent_of_code_2025::template::runner::{OutputFormat, Solution};
+use advent_of_code_2025::template::set_year_from_package;
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn main() {
+    set_year_from_package(env!("CARGO_PKG_NAME"));
+    if let Err(e) = run(pico_args::Arguments::from_env()) {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    }
+}
+
+fn run(mut args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let format: OutputFormat = args.opt_value_from_str("--format")?.unwrap_or_default();
+    let timeout = args
+        .opt_value_from_str("--timeout")?
+        .map(Duration::from_secs);
+
+    match args.subcommand()?.as_deref() {
+        Some("all") => {
+            let jobs = args.opt_value_from_str("--jobs")?;
+            all::run(SOLUTIONS, jobs.unwrap_or(1), timeout, format);
+        }
+        Some("time") => {
+            let run_all = args.contains("--all");
+            let store = args.contains("--store");
+            let threshold = args.opt_value_from_str("--threshold")?;
+            let day = args.opt_free_from_str()?;
+            time::run(
+                SOLUTIONS,
+                day,
+                run_all,
+                store,
+                threshold.unwrap_or(time::DEFAULT_REGRESSION_THRESHOLD),
+                timeout,
+                format,
+            );
+        }
+        Some("verify") => verify::run(SOLUTIONS, timeout),
+        Some(x) => {
+            eprintln!("Unknown command: {x}");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("No command specified.");
+            process::exit(1);
+        }
+    }
+
+    Ok(())
+}
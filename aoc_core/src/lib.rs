@@ -0,0 +1,4 @@
+//! The template and utilities shared by every year of the repository.
+//! Each year's crate depends on this crate, so fixes to the runner, the commands or the utilities land once.
+pub mod template;
+pub mod utils;
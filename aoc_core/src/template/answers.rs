@@ -0,0 +1,443 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    aoc_cli::{decode_html, SubmissionOutcome},
+    Day,
+};
+
+/// A single judged submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+/// Represents the submission history for one part of a day.
+#[derive(Clone, Debug)]
+pub struct PartAnswers {
+    pub day: Day,
+    pub part: u8,
+    pub accepted: Option<String>,
+    pub submissions: Vec<Submission>,
+}
+
+/// Result of checking a candidate answer against the ledger before submitting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    /// Nothing is known that speaks against submitting the answer.
+    Unknown,
+    /// The part has already been solved with this answer.
+    Accepted,
+    /// The part has already been solved with a different answer.
+    Solved(String),
+    /// The answer was submitted before and rejected with the given verdict.
+    Rejected(SubmissionOutcome),
+    /// The answer is not below a previously submitted answer that was too high.
+    AboveBound(String),
+    /// The answer is not above a previously submitted answer that was too low.
+    BelowBound(String),
+}
+
+/// Ledger of submitted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<PartAnswers>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(answers_file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty ledger.
+    pub fn read_from_file() -> Result<Self, String> {
+        Answers::read_from_path(&answers_file_path())
+    }
+
+    /// Rehydrate answers from a JSON file at `path`, e.g. of another year. If not present, returns an empty ledger.
+    /// Fails if the file exists but cannot be read or parsed, so a damaged ledger is never mistaken for an empty one.
+    pub fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::try_from(contents)
+                .map_err(|e| format!("{} is malformed: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&PartAnswers> {
+        self.data.iter().find(|a| a.day == day && a.part == part)
+    }
+
+    /// Checks what is already known about an answer before it is submitted.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> AnswerCheck {
+        let Some(entry) = self.get(day, part) else {
+            return AnswerCheck::Unknown;
+        };
+
+        if let Some(accepted) = &entry.accepted {
+            if accepted == answer {
+                return AnswerCheck::Accepted;
+            }
+            return AnswerCheck::Solved(accepted.clone());
+        }
+
+        if let Some(previous) = entry.submissions.iter().find(|s| s.answer == answer) {
+            return AnswerCheck::Rejected(previous.outcome.clone());
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return AnswerCheck::Unknown;
+        };
+        let (lower, upper) = entry.bounds();
+
+        match (lower, upper) {
+            (_, Some(upper)) if value >= upper => AnswerCheck::AboveBound(upper.to_string()),
+            (Some(lower), _) if value <= lower => AnswerCheck::BelowBound(lower.to_string()),
+            _ => AnswerCheck::Unknown,
+        }
+    }
+
+    /// Records a submission. Only verdicts that judge the answer itself are kept.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, outcome: &SubmissionOutcome) {
+        if outcome_to_str(outcome).is_none() {
+            return;
+        }
+
+        let index = match self
+            .data
+            .iter()
+            .position(|a| a.day == day && a.part == part)
+        {
+            Some(index) => index,
+            None => {
+                self.data.push(PartAnswers {
+                    day,
+                    part,
+                    accepted: None,
+                    submissions: vec![],
+                });
+                self.data.len() - 1
+            }
+        };
+        let entry = &mut self.data[index];
+
+        entry.submissions.push(Submission {
+            answer: answer.to_string(),
+            outcome: outcome.clone(),
+        });
+        if *outcome == SubmissionOutcome::Correct {
+            entry.accepted = Some(answer.to_string());
+        }
+
+        self.data.sort_unstable_by_key(|a| (a.day, a.part));
+    }
+}
+
+impl PartAnswers {
+    /// Returns the highest answer known to be too low and the lowest answer known to be too high.
+    pub fn bounds(&self) -> (Option<i128>, Option<i128>) {
+        let numeric = |outcome: SubmissionOutcome| {
+            self.submissions
+                .iter()
+                .filter(move |s| s.outcome == outcome)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+        (
+            numeric(SubmissionOutcome::TooLow).max(),
+            numeric(SubmissionOutcome::TooHigh).min(),
+        )
+    }
+}
+
+/// The answers of a day's solved parts as shown on its downloaded puzzle page in `data/puzzles/DD.md`.
+/// Lets `verify` check parts that were solved before the ledger existed or on another machine.
+pub fn puzzle_answers(day: Day) -> Vec<String> {
+    let path = crate::template::year_directory()
+        .join("data")
+        .join("puzzles")
+        .join(format!("{day}.md"));
+    fs::read_to_string(path)
+        .map(|puzzle| parse_puzzle_answers(&puzzle))
+        .unwrap_or_default()
+}
+
+fn parse_puzzle_answers(puzzle: &str) -> Vec<String> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+    puzzle
+        .match_indices(PREFIX)
+        .filter_map(|(start, _)| {
+            let answer = &puzzle[start + PREFIX.len()..];
+            let end = answer.find("</code>")?;
+            Some(decode_html(&answer[..end]))
+        })
+        .collect()
+}
+
+fn answers_file_path() -> PathBuf {
+    crate::template::year_directory()
+        .join("data")
+        .join("answers.json")
+}
+
+fn outcome_to_str(outcome: &SubmissionOutcome) -> Option<&'static str> {
+    match outcome {
+        SubmissionOutcome::Correct => Some("correct"),
+        SubmissionOutcome::Incorrect => Some("incorrect"),
+        SubmissionOutcome::TooHigh => Some("too_high"),
+        SubmissionOutcome::TooLow => Some("too_low"),
+        _ => None,
+    }
+}
+
+fn outcome_from_str(s: &str) -> Option<SubmissionOutcome> {
+    match s {
+        "correct" => Some(SubmissionOutcome::Correct),
+        "incorrect" => Some(SubmissionOutcome::Incorrect),
+        "too_high" => Some(SubmissionOutcome::TooHigh),
+        "too_low" => Some(SubmissionOutcome::TooLow),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(PartAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartAnswers> for JsonValue {
+    fn from(value: &PartAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "accepted".into(),
+            match &value.accepted {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        let submissions = value
+            .submissions
+            .iter()
+            .filter_map(|s| {
+                let mut submission: HashMap<String, JsonValue> = HashMap::new();
+                submission.insert("answer".into(), JsonValue::String(s.answer.clone()));
+                submission.insert(
+                    "verdict".into(),
+                    JsonValue::String(outcome_to_str(&s.outcome)?.into()),
+                );
+                Some(JsonValue::Object(submission))
+            })
+            .collect();
+        map.insert("submissions".into(), JsonValue::Array(submissions));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answers.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| *part == 1 || *part == 2)
+            .ok_or("Expected answers.part to be 1 or 2.")?;
+
+        let accepted = json
+            .get("accepted")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answers.accepted to be null or string.")?;
+
+        let submissions = json
+            .get("submissions")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected answers.submissions to be an array.")?
+            .iter()
+            .map(|s| {
+                let s = s.get::<HashMap<String, JsonValue>>()?;
+                Some(Submission {
+                    answer: s.get("answer")?.get::<String>()?.clone(),
+                    outcome: outcome_from_str(s.get("verdict")?.get::<String>()?)?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Expected answers.submissions to contain answers and verdicts.")?;
+
+        Ok(PartAnswers {
+            day,
+            part,
+            accepted: accepted.cloned(),
+            submissions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_puzzle_answers, AnswerCheck, Answers};
+    use crate::{day, template::aoc_cli::SubmissionOutcome};
+    use std::fs;
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.record(day!(5), 1, "4242", &SubmissionOutcome::TooHigh);
+        answers.record(day!(5), 1, "100", &SubmissionOutcome::TooLow);
+        answers.record(day!(5), 1, "abc", &SubmissionOutcome::Incorrect);
+        answers.record(day!(1), 2, "7", &SubmissionOutcome::Correct);
+        answers
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(5), 1, "4242"),
+            AnswerCheck::Rejected(SubmissionOutcome::TooHigh)
+        );
+        assert_eq!(
+            answers.check(day!(5), 1, "abc"),
+            AnswerCheck::Rejected(SubmissionOutcome::Incorrect)
+        );
+    }
+
+    #[test]
+    fn warns_about_answers_outside_bounds() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(5), 1, "5000"),
+            AnswerCheck::AboveBound("4242".into())
+        );
+        assert_eq!(
+            answers.check(day!(5), 1, "99"),
+            AnswerCheck::BelowBound("100".into())
+        );
+        assert_eq!(answers.check(day!(5), 1, "2000"), AnswerCheck::Unknown);
+        assert_eq!(answers.check(day!(5), 2, "2000"), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn handles_solved_parts() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.check(day!(1), 2, "7"), AnswerCheck::Accepted);
+        assert_eq!(
+            answers.check(day!(1), 2, "8"),
+            AnswerCheck::Solved("7".into())
+        );
+    }
+
+    #[test]
+    fn ignores_unjudged_submissions() {
+        let mut answers = Answers::default();
+        answers.record(day!(3), 1, "12", &SubmissionOutcome::RateLimited(None));
+        answers.record(day!(3), 1, "12", &SubmissionOutcome::WrongLevel);
+        assert!(answers.data.is_empty());
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = get_mock_answers();
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data.len(), 2);
+        assert_eq!(parsed.get(day!(1), 2).unwrap().accepted, Some("7".into()));
+        assert_eq!(parsed.get(day!(5), 1).unwrap().submissions.len(), 3);
+        assert_eq!(
+            parsed.get(day!(5), 1).unwrap().bounds(),
+            (Some(100), Some(4242))
+        );
+    }
+
+    #[test]
+    fn fails_for_damaged_ledgers() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.json");
+
+        assert_eq!(Answers::read_from_path(&path).unwrap().data.len(), 0);
+        fs::write(&path, "{ \"data\": [").unwrap();
+        assert!(Answers::read_from_path(&path).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json =
+            r#"{ "data": [{ "day": "01", "part": 3, "accepted": null, "submissions": [] }] }"#
+                .to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn parses_answers_of_solved_parts() {
+        let puzzle = "<h2>--- Day 1 ---</h2><p>Total is <code><em>11</em></code>.</p>\n<p>Your puzzle answer was <code>1234</code>.</p>\n<h2 id=\"part2\">--- Part Two ---</h2>\n<p>Your puzzle answer was <code>a&amp;b</code>.</p>";
+        assert_eq!(parse_puzzle_answers(puzzle), vec!["1234", "a&b"]);
+        assert!(parse_puzzle_answers("<h2>--- Day 1 ---</h2>").is_empty());
+    }
+}
@@ -0,0 +1,486 @@
+/// Minimal HTTP client for the Advent of Code website.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+static SESSION_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+static BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+static USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/PaulTreitel/Advent-of-Code-Rust)"
+);
+
+#[derive(Debug)]
+pub enum AocCommandError {
+    SessionNotFound,
+    RequestFailed(String),
+    BadStatus(u16),
+    RateLimited,
+    IO(io::Error),
+}
+
+impl Display for AocCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocCommandError::SessionNotFound => write!(
+                f,
+                "no session cookie found in ${SESSION_ENV_VAR} or ~/{SESSION_FILE_NAME}."
+            ),
+            AocCommandError::RequestFailed(e) => write!(f, "request failed: {e}"),
+            AocCommandError::BadStatus(status) => {
+                write!(f, "server responded with HTTP status {status}.")
+            }
+            AocCommandError::RateLimited => {
+                write!(f, "rate limited by the server, try again later.")
+            }
+            AocCommandError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocCommandError {
+    fn from(e: io::Error) -> Self {
+        AocCommandError::IO(e)
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after a previous one. Contains the remaining wait if given.
+    RateLimited(Option<Duration>),
+    /// The part has already been solved or is not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    /// Classifies the text of a submission response.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if response.contains("You gave an answer too recently") {
+            SubmissionOutcome::RateLimited(parse_wait_time(response))
+        } else if response.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::WrongLevel
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                SubmissionOutcome::TooHigh
+            } else if response.contains("your answer is too low") {
+                SubmissionOutcome::TooLow
+            } else {
+                SubmissionOutcome::Incorrect
+            }
+        } else {
+            SubmissionOutcome::Unknown(response.to_string())
+        }
+    }
+
+    /// Process exit code used to report this outcome to scripts wrapping `cargo solve --submit`.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SubmissionOutcome::Correct => 0,
+            SubmissionOutcome::Incorrect => 2,
+            SubmissionOutcome::TooHigh => 3,
+            SubmissionOutcome::TooLow => 4,
+            SubmissionOutcome::RateLimited(_) => 5,
+            SubmissionOutcome::WrongLevel => 6,
+            SubmissionOutcome::Unknown(_) => 7,
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "{ANSI_BOLD}✔ Correct!{ANSI_RESET}"),
+            SubmissionOutcome::Incorrect => write!(f, "{ANSI_BOLD}✖ Incorrect.{ANSI_RESET}"),
+            SubmissionOutcome::TooHigh => {
+                write!(f, "{ANSI_BOLD}✖ Incorrect:{ANSI_RESET} answer is too high.")
+            }
+            SubmissionOutcome::TooLow => {
+                write!(f, "{ANSI_BOLD}✖ Incorrect:{ANSI_RESET} answer is too low.")
+            }
+            SubmissionOutcome::RateLimited(Some(wait)) => write!(
+                f,
+                "{ANSI_BOLD}⏳ Rate limited:{ANSI_RESET} wait {}s before submitting again.",
+                wait.as_secs()
+            ),
+            SubmissionOutcome::RateLimited(None) => write!(
+                f,
+                "{ANSI_BOLD}⏳ Rate limited:{ANSI_RESET} wait before submitting again."
+            ),
+            SubmissionOutcome::WrongLevel => write!(
+                f,
+                "{ANSI_BOLD}✖ Wrong level:{ANSI_RESET} this part is already solved or still locked."
+            ),
+            SubmissionOutcome::Unknown(response) => {
+                write!(
+                    f,
+                    "{ANSI_BOLD}? Unrecognized response:{ANSI_RESET}\n{response}"
+                )
+            }
+        }
+    }
+}
+
+/// Checks that a session cookie is available.
+pub fn check() -> Result<(), AocCommandError> {
+    read_session().map(|_| ())
+}
+
+/// Fetches the puzzle description, stores it and prints it to the terminal.
+pub fn read(day: Day) -> Result<String, AocCommandError> {
+    let session = read_session()?;
+    let year = crate::template::get_year_exit_on_fail();
+    println!("Fetching puzzle for day {}, {year}...", day.into_inner());
+
+    let puzzle = fetch_puzzle(&session, year, day)?;
+    write_creating_dir(&get_puzzle_path(day), &puzzle)?;
+
+    let description = html_to_text(&puzzle);
+    println!("{description}");
+    Ok(description)
+}
+
+pub fn download(day: Day) -> Result<(), AocCommandError> {
+    let session = read_session()?;
+    let year = crate::template::get_year_exit_on_fail();
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    let input = get(&format!("/{year}/day/{}/input", day.into_inner()), &session)?;
+    write_creating_dir(&input_path, &input)?;
+
+    let puzzle = fetch_puzzle(&session, year, day)?;
+    write_creating_dir(&puzzle_path, &puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", input_path.display());
+    println!("🎄 Successfully wrote puzzle to \"{}\".", puzzle_path.display());
+    Ok(())
+}
+
+/// Posts an answer and classifies the server's response.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<SubmissionOutcome, AocCommandError> {
+    let session = read_session()?;
+    let year = crate::template::get_year_exit_on_fail();
+    let path = format!("/{year}/day/{}/answer", day.into_inner());
+    let response = post(
+        &path,
+        &session,
+        &[("level", &part.to_string()), ("answer", result)],
+    )?;
+    let text = html_to_text(&extract_articles(&response));
+    Ok(SubmissionOutcome::parse(&text))
+}
+
+fn get_input_path(day: Day) -> PathBuf {
+    crate::template::year_directory()
+        .join("data")
+        .join("inputs")
+        .join(format!("{day}.txt"))
+}
+
+fn get_puzzle_path(day: Day) -> PathBuf {
+    crate::template::year_directory()
+        .join("data")
+        .join("puzzles")
+        .join(format!("{day}.md"))
+}
+
+/// Write a file, creating its directory first, e.g. a missing `data/puzzles`.
+fn write_creating_dir(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+fn fetch_puzzle(session: &str, year: u32, day: Day) -> Result<String, AocCommandError> {
+    let page = get(&format!("/{year}/day/{}", day.into_inner()), session)?;
+    Ok(extract_articles(&page))
+}
+
+fn base_url() -> String {
+    env::var(BASE_URL_ENV_VAR)
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn session_file_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE_NAME))
+}
+
+fn read_session() -> Result<String, AocCommandError> {
+    let session = match env::var(SESSION_ENV_VAR) {
+        Ok(session) => session,
+        Err(_) => session_file_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .ok_or(AocCommandError::SessionNotFound)?,
+    };
+    let session = session.trim();
+    if session.is_empty() {
+        return Err(AocCommandError::SessionNotFound);
+    }
+    Ok(session.to_string())
+}
+
+fn get(path: &str, session: &str) -> Result<String, AocCommandError> {
+    let response = ureq::get(&format!("{}{path}", base_url()))
+        .set("User-Agent", USER_AGENT)
+        .set("Cookie", &format!("session={session}"))
+        .call();
+    read_response(response)
+}
+
+fn post(path: &str, session: &str, form: &[(&str, &str)]) -> Result<String, AocCommandError> {
+    let response = ureq::post(&format!("{}{path}", base_url()))
+        .set("User-Agent", USER_AGENT)
+        .set("Cookie", &format!("session={session}"))
+        .send_form(form);
+    read_response(response)
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocCommandError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(429, _)) => Err(AocCommandError::RateLimited),
+        Err(ureq::Error::Status(status, _)) => Err(AocCommandError::BadStatus(status)),
+        Err(e) => Err(AocCommandError::RequestFailed(e.to_string())),
+    }
+}
+
+/// Parses the remaining wait from e.g. "You have 1m 5s left to wait."
+fn parse_wait_time(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left")?;
+
+    let mut secs = 0;
+    for token in response[start..end].split_whitespace() {
+        let (index, unit) = token.char_indices().last()?;
+        let value: u64 = token[..index].parse().ok()?;
+        let factor = match unit {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        secs = value.checked_mul(factor)?.checked_add(secs)?;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Returns the contents of every `<article>` element on a page, which is where
+/// the puzzle description and submission responses live.
+fn extract_articles(html: &str) -> String {
+    let mut articles: Vec<&str> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end + "</article>".len()..];
+
+        // the answer of a solved part follows its description.
+        let answer = rest.trim_start();
+        if answer.starts_with("<p>Your puzzle answer was") {
+            if let Some(answer_end) = answer.find("</p>") {
+                articles.push(&answer[..answer_end + "</p>".len()]);
+            }
+        }
+    }
+
+    if articles.is_empty() {
+        return html.to_string();
+    }
+    articles.join("\n")
+}
+
+/// Strips tags and decodes the handful of entities used on the site.
+fn html_to_text(html: &str) -> String {
+    decode_html(html).trim().to_string()
+}
+
+/// Strips tags and decodes entities while keeping all whitespace, e.g. of a code block.
+pub(crate) fn decode_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        extract_articles, html_to_text, parse_wait_time, read_response, AocCommandError,
+        SubmissionOutcome,
+    };
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    /// Serves a single canned HTTP response on a random local port.
+    fn serve_once(status: &str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let status = status.to_string();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let _ = stream.read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn reads_successful_responses() {
+        let url = serve_once("200 OK", "1\n2\n3\n");
+        let body = read_response(ureq::get(&url).call()).unwrap();
+        assert_eq!(body, "1\n2\n3\n");
+    }
+
+    #[test]
+    fn maps_rate_limiting() {
+        let url = serve_once("429 Too Many Requests", "");
+        let err = read_response(ureq::get(&url).call()).unwrap_err();
+        assert!(matches!(err, AocCommandError::RateLimited));
+    }
+
+    #[test]
+    fn maps_bad_status() {
+        let url = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+        let err = read_response(ureq::get(&url).call()).unwrap_err();
+        assert!(matches!(err, AocCommandError::BadStatus(400)));
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<main><article class=\"day-desc\"><h2>Day 1</h2></article><p>x</p><article><p>Part 2</p></article></main>";
+        assert_eq!(extract_articles(html), "<h2>Day 1</h2>\n<p>Part 2</p>");
+    }
+
+    #[test]
+    fn keeps_answers_of_solved_parts() {
+        let html = "<main><article><h2>Day 1</h2></article>\n<p>Your puzzle answer was <code>42</code>.</p><p>The first half is complete!</p><article><p>Part 2</p></article></main>";
+        assert_eq!(
+            extract_articles(html),
+            "<h2>Day 1</h2>\n<p>Your puzzle answer was <code>42</code>.</p>\n<p>Part 2</p>"
+        );
+    }
+
+    #[test]
+    fn converts_html_to_text() {
+        let html = "<p>That's the <em>right</em> answer! &lt;3 &amp; more</p>";
+        assert_eq!(html_to_text(html), "That's the right answer! <3 & more");
+    }
+
+    #[test]
+    fn classifies_submission_responses() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                SubmissionOutcome::Correct,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                SubmissionOutcome::Incorrect,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                SubmissionOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmissionOutcome::TooLow,
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                SubmissionOutcome::WrongLevel,
+            ),
+        ];
+        for (response, expected) in cases {
+            assert_eq!(SubmissionOutcome::parse(response), expected);
+        }
+    }
+
+    #[test]
+    fn parses_rate_limit_wait() {
+        let response = "You gave an answer too recently. You have 1m 5s left to wait.";
+        assert_eq!(
+            SubmissionOutcome::parse(response),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+        let response = "You gave an answer too recently. You have 37s left to wait.";
+        assert_eq!(
+            SubmissionOutcome::parse(response),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(37)))
+        );
+    }
+
+    #[test]
+    fn ignores_malformed_wait_times() {
+        assert_eq!(parse_wait_time("You have 5分 left to wait."), None);
+        assert_eq!(parse_wait_time("You have 5x left to wait."), None);
+        assert_eq!(parse_wait_time("You have s left to wait."), None);
+        assert_eq!(
+            parse_wait_time("You have 9999999999999999h left to wait."),
+            None
+        );
+        assert_eq!(
+            parse_wait_time("You have 9999999999999999999m left to wait."),
+            None
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_responses() {
+        let outcome = SubmissionOutcome::parse("Something else entirely.");
+        assert_eq!(outcome.exit_code(), 7);
+        assert!(matches!(outcome, SubmissionOutcome::Unknown(_)));
+    }
+}
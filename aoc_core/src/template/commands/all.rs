@@ -0,0 +1,44 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::all_days;
+use crate::template::run_multi::{child_commands, has_failures, run_multi};
+use crate::template::runner::{OutputFormat, Solution};
+
+pub fn handle(is_release: bool, jobs: Option<usize>, timeout: Option<u64>, format: OutputFormat) {
+    child_commands::run_solutions_exit_on_fail(&args(jobs, timeout, format), is_release);
+}
+
+/// Arguments that make a year's `solutions` binary run every day.
+pub fn args(jobs: Option<usize>, timeout: Option<u64>, format: OutputFormat) -> Vec<String> {
+    let mut args = vec!["all".to_string()];
+    if let Some(jobs) = jobs {
+        args.extend(["--jobs".into(), jobs.to_string()]);
+    }
+    if let Some(timeout) = timeout {
+        args.extend(["--timeout".into(), timeout.to_string()]);
+    }
+    args.extend(format.to_args());
+    args
+}
+
+/// Run every registered solution in-process on `jobs` threads. Called by the year's `solutions` binary.
+/// Exits with a non-zero status if a day panicked or timed out.
+pub fn run(
+    solutions: &'static [Solution],
+    jobs: usize,
+    timeout: Option<Duration>,
+    format: OutputFormat,
+) {
+    let results = run_multi(
+        solutions,
+        &all_days().collect(),
+        false,
+        jobs,
+        timeout,
+        format,
+    );
+    if has_failures(&results) {
+        process::exit(1);
+    }
+}
@@ -1,8 +1,9 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{check_day_exit_on_fail, Day};
 
 pub fn handle(day: Day, test: Option<String>, dhat: bool) {
+    check_day_exit_on_fail(day);
     let year = crate::template::get_year_exit_on_fail();
     let year = format!("advent_of_code_{}", year);
     let mut cmd_args = vec![
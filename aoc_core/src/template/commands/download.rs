@@ -0,0 +1,16 @@
+use crate::template::{aoc_cli, check_day_exit_on_fail, Day};
+use std::process;
+
+pub fn handle(day: Day) {
+    check_day_exit_on_fail(day);
+
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    if let Err(e) = aoc_cli::download(day) {
+        eprintln!("failed to download puzzle: {e}");
+        process::exit(1);
+    };
+}
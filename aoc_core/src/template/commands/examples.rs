@@ -0,0 +1,273 @@
+use std::{fs, process};
+
+use crate::template::aoc_cli::decode_html;
+use crate::template::{check_day_exit_on_fail, get_year_exit_on_fail, repository_root, Day};
+
+/// Example input and expected answer of one part, as found in the puzzle description.
+#[derive(Debug, PartialEq, Eq)]
+struct PartExample {
+    example: Option<String>,
+    answer: Option<String>,
+}
+
+pub fn handle(day: Day) {
+    check_day_exit_on_fail(day);
+    let year = get_year_exit_on_fail();
+    let puzzle_path = format!("{year}/data/puzzles/{day}.md");
+    let module_path = format!("{year}/src/bin/{day}.rs");
+
+    let root = repository_root();
+    let Ok(puzzle) = fs::read_to_string(root.join(&puzzle_path)) else {
+        eprintln!(
+            "Could not read \"{puzzle_path}\". Type `cargo download {}` to download the puzzle first.",
+            day.into_inner()
+        );
+        process::exit(1);
+    };
+    let parts = parse_puzzle(&puzzle);
+
+    // part two only gets its own example file if it uses a different example than part one.
+    let mut example_parts = vec![];
+    for (part, example) in parts.iter().zip(1u8..) {
+        let Some(input) = &part.example else {
+            continue;
+        };
+        if example > 1 && parts[0].example.as_ref() == Some(input) {
+            continue;
+        }
+        let example_path = match example {
+            1 => format!("{year}/data/examples/{day}.txt"),
+            _ => format!("{year}/data/examples/{day}-{example}.txt"),
+        };
+        if write_example(&example_path, input) {
+            example_parts.push(example);
+        }
+    }
+
+    let Ok(mut module) = fs::read_to_string(root.join(&module_path)) else {
+        eprintln!(
+            "Could not read \"{module_path}\". Type `cargo scaffold {}` to create it.",
+            day.into_inner()
+        );
+        process::exit(1);
+    };
+    let mut is_changed = false;
+    for (part, number) in parts.iter().zip(1u8..) {
+        let Some(answer) = &part.answer else {
+            println!("No expected answer found for part {number}.");
+            continue;
+        };
+        let Some(expected) = expected_value(&module, number, answer) else {
+            println!("Kept the existing test of part {number}: {answer:?} is not a number and the part does not return a String.");
+            continue;
+        };
+        let reads_part_file = number > 1 && example_parts.contains(&number);
+        match fill_in_test(&module, number, &expected, reads_part_file) {
+            Some(filled) => {
+                module = filled;
+                is_changed = true;
+                println!("Expecting {answer} for part {number} in \"{module_path}\"");
+            }
+            None => println!("Kept the existing test of part {number}."),
+        }
+    }
+    if !is_changed {
+        return;
+    }
+    if let Err(e) = fs::write(root.join(&module_path), module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
+
+/// Write an example file unless it already has other contents. Returns whether the file holds `input`.
+fn write_example(path: &str, input: &str) -> bool {
+    let full_path = repository_root().join(path);
+    let existing = fs::read_to_string(&full_path).unwrap_or_default();
+    if existing == input {
+        return true;
+    }
+    if !existing.trim().is_empty() {
+        println!("Kept the existing example file \"{path}\"");
+        return false;
+    }
+    match fs::write(&full_path, input) {
+        Ok(()) => {
+            println!("Wrote example to \"{path}\"");
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Split a stored puzzle description into its parts and find their example and answer.
+fn parse_puzzle(puzzle: &str) -> Vec<PartExample> {
+    let parts = match puzzle.find("<h2 id=\"part2\">") {
+        Some(i) => vec![&puzzle[..i], &puzzle[i..]],
+        None => vec![puzzle],
+    };
+    parts
+        .into_iter()
+        .map(|part| PartExample {
+            example: largest_code_block(part),
+            answer: last_answer(part),
+        })
+        .collect()
+}
+
+/// The `<pre><code>` block with the most lines, which is the example input of most puzzles.
+fn largest_code_block(html: &str) -> Option<String> {
+    let mut largest: Option<String> = None;
+    let mut rest = html;
+    while let Some(start) = rest.find("<pre><code>") {
+        let content = &rest[start + "<pre><code>".len()..];
+        let Some(end) = content.find("</code></pre>") else {
+            break;
+        };
+        let block = decode_html(&content[..end]);
+        if largest
+            .as_ref()
+            .is_none_or(|l| block.lines().count() > l.lines().count())
+        {
+            largest = Some(block);
+        }
+        rest = &content[end..];
+    }
+    largest
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| {
+            let mut block = block.trim_end_matches('\n').to_string();
+            block.push('\n');
+            block
+        })
+}
+
+/// The last emphasized code in a part, where puzzles state the answer for the example.
+fn last_answer(html: &str) -> Option<String> {
+    let start = html.rfind("<code><em>")? + "<code><em>".len();
+    let end = start + html[start..].find("</em></code>")?;
+    let answer = decode_html(&html[start..end]).trim().to_string();
+    (!answer.is_empty()).then_some(answer)
+}
+
+/// The value a test of `part` expects for `answer`, e.g. `Some(11)`.
+/// Returns `None` if the answer is not a number and the part does not return a `String`, as the test would not compile.
+fn expected_value(module: &str, part: u8, answer: &str) -> Option<String> {
+    let name = match part {
+        1 => "fn part_one(",
+        _ => "fn part_two(",
+    };
+    let signature = module.find(name).map(|start| {
+        let signature = &module[start..];
+        &signature[..signature.find('{').unwrap_or(signature.len())]
+    });
+    let returns_string = signature
+        .and_then(|signature| signature.split("->").nth(1))
+        .is_some_and(|output| output.split_whitespace().collect::<String>() == "Option<String>");
+
+    if returns_string {
+        Some(format!("Some({answer:?}.to_string())"))
+    } else if answer.parse::<i64>().is_ok() {
+        Some(format!("Some({answer})"))
+    } else {
+        None
+    }
+}
+
+/// Replace the `None` expected by the scaffolded test of `part` with `expected`.
+/// Returns `None` if the test does not exist or already expects something else.
+fn fill_in_test(module: &str, part: u8, expected: &str, reads_part_file: bool) -> Option<String> {
+    let name = match part {
+        1 => "fn test_part_one()",
+        _ => "fn test_part_two()",
+    };
+    let start = module.find(name)?;
+    let end = start + module[start..].find("\n    }\n")?;
+    let test = &module[start..end];
+    if !test.contains("assert_eq!(result, None);") {
+        return None;
+    }
+
+    let mut test = test.replace(
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, {expected});"),
+    );
+    if reads_part_file {
+        test = test.replace(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {part})"),
+        );
+    }
+    Some(format!("{}{test}{}", &module[..start], &module[end..]))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{expected_value, fill_in_test, parse_puzzle, PartExample};
+
+    const PUZZLE: &str = "<h2>--- Day 1: Test ---</h2><p>For example:</p>\n<pre><code>a&lt;b\n1 2\n</code></pre>\n<p>Use <code>x</code>, for a total of <code><em>11</em></code>.</p>\n<h2 id=\"part2\">--- Part Two ---</h2><p>Now:</p>\n<pre><code><em>x</em>y\nz\n</code></pre>\n<p>This gives <code><em>abc</em></code>.</p>";
+
+    #[test]
+    fn parses_examples_and_answers() {
+        assert_eq!(
+            parse_puzzle(PUZZLE),
+            vec![
+                PartExample {
+                    example: Some("a<b\n1 2\n".into()),
+                    answer: Some("11".into()),
+                },
+                PartExample {
+                    example: Some("xy\nz\n".into()),
+                    answer: Some("abc".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_part_one_only() {
+        let parts = parse_puzzle("<p>Nothing to see</p>");
+        assert_eq!(
+            parts,
+            vec![PartExample {
+                example: None,
+                answer: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn only_expects_strings_from_parts_that_return_them() {
+        let module = include_str!("../template.txt");
+        assert_eq!(expected_value(module, 1, "11"), Some("Some(11)".into()));
+        assert_eq!(expected_value(module, 2, "abc"), None);
+
+        let module = module.replace(
+            "pub fn part_two(input: &str) -> Option<u64>",
+            "pub fn part_two(input: &str) -> Option< String >",
+        );
+        assert_eq!(
+            expected_value(&module, 2, "abc"),
+            Some("Some(\"abc\".to_string())".into())
+        );
+        assert_eq!(
+            expected_value(&module, 2, "12"),
+            Some("Some(\"12\".to_string())".into())
+        );
+    }
+
+    #[test]
+    fn fills_in_scaffolded_tests() {
+        let module = include_str!("../template.txt");
+        let module = fill_in_test(module, 1, "Some(11)", false).unwrap();
+        let module = fill_in_test(&module, 2, "Some(\"abc\".to_string())", true).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(11));"));
+        assert!(module.contains("assert_eq!(result, Some(\"abc\".to_string()));"));
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2)"));
+        assert_eq!(module.matches("read_file(\"examples\", DAY)").count(), 1);
+        assert_eq!(fill_in_test(&module, 1, "Some(12)", false), None);
+    }
+}
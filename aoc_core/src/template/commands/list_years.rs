@@ -0,0 +1,178 @@
+use std::{fs, path::Path, str::FromStr};
+
+use toml_edit::{DocumentMut, Item};
+
+use crate::template::{
+    answers::Answers, commands::set_year, repository_root, timings::Timings, workspace_years, Day,
+    ANSI_BOLD, ANSI_RESET,
+};
+
+/// Progress of a single year of the workspace.
+struct YearSummary {
+    year: u32,
+    days: usize,
+    parts: usize,
+    solved_parts: usize,
+    total_millis: Option<f64>,
+}
+
+pub fn handle() {
+    let current = set_year::configured_year();
+    let summaries: Vec<YearSummary> = workspace_years().into_iter().map(summarize).collect();
+    if summaries.is_empty() {
+        println!("No years in the workspace yet, create one with `cargo new-year <year>`.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}Year  Days  Solved  Total time{ANSI_RESET}");
+    for summary in summaries {
+        let total_time = match summary.total_millis {
+            Some(millis) => format!("{millis:.2}ms"),
+            None => "-".into(),
+        };
+        let marker = if current == Some(summary.year) {
+            " (current)"
+        } else {
+            ""
+        };
+        println!(
+            "{}  {:>4}  {:>6}  {:>10}{}",
+            summary.year,
+            summary.days,
+            format!("{}/{}", summary.solved_parts, summary.parts),
+            total_time,
+            marker
+        );
+    }
+}
+
+fn summarize(year: u32) -> YearSummary {
+    let year_root = repository_root().join(year.to_string());
+    let data = year_root.join("data");
+    let answers = Answers::read_from_path(&data.join("answers.json")).unwrap_or_else(|e| {
+        eprintln!("Ignoring the answer ledger of {year}: {e}");
+        Answers::default()
+    });
+    let timings = Timings::read_from_path(&data.join("timings.json")).unwrap_or_else(|e| {
+        eprintln!("Ignoring the timings of {year}: {e}");
+        Timings::default()
+    });
+
+    let days = scaffolded_days(&year_root);
+    let parts = days
+        .iter()
+        .map(|(day, _)| usize::from(day.parts(year)))
+        .sum();
+    let solved_parts = days
+        .iter()
+        .map(|(day, source)| {
+            let tested = solved_in_tests(source);
+            let listed = fs::read_to_string(data.join("examples").join(format!("{day}.toml")))
+                .map(|manifest| parts_in_manifest(&manifest))
+                .unwrap_or_default();
+            (1..=day.parts(year))
+                .filter(|&part| {
+                    tested[part as usize - 1]
+                        || listed[part as usize - 1]
+                        || answers
+                            .get(*day, part)
+                            .is_some_and(|a| a.accepted.is_some())
+                })
+                .count()
+        })
+        .sum();
+
+    YearSummary {
+        year,
+        days: days.len(),
+        parts,
+        solved_parts,
+        total_millis: (!timings.data.is_empty()).then(|| timings.total_millis()),
+    }
+}
+
+/// Days with a solution file in `src/bin/`, with the file's source.
+fn scaffolded_days(year_root: &Path) -> Vec<(Day, String)> {
+    let Ok(entries) = fs::read_dir(year_root.join("src").join("bin")) else {
+        return vec![];
+    };
+    let mut days: Vec<(Day, String)> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            let day = Day::from_str(path.file_stem()?.to_str()?).ok()?;
+            let source = fs::read_to_string(&path).ok()?;
+            Some((day, source))
+        })
+        .collect();
+    days.sort_by_key(|(day, _)| *day);
+    days
+}
+
+/// Whether the tests of part one and two in a solution's source check an actual answer.
+/// A freshly scaffolded test still expects `None`.
+fn solved_in_tests(source: &str) -> [bool; 2] {
+    ["one", "two"].map(|part| {
+        let tests: Vec<&str> = source
+            .split("fn test_part_")
+            .skip(1)
+            .filter(|test| test.starts_with(part))
+            .map(|test| test.split("fn ").next().unwrap_or(test))
+            .collect();
+        !tests.is_empty()
+            && tests
+                .iter()
+                .all(|test| !test.contains("assert_eq!(result, None)"))
+    })
+}
+
+/// Whether part one and two have an example with an answer in an example manifest for `solution_tests!()`.
+fn parts_in_manifest(manifest: &str) -> [bool; 2] {
+    let Ok(manifest) = manifest.parse::<DocumentMut>() else {
+        return [false, false];
+    };
+    let parts: Vec<i64> = manifest
+        .get("example")
+        .and_then(Item::as_array_of_tables)
+        .into_iter()
+        .flatten()
+        .filter(|example| example.contains_key("answer"))
+        .filter_map(|example| example.get("part")?.as_integer())
+        .collect();
+    [parts.contains(&1), parts.contains(&2)]
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parts_in_manifest, solved_in_tests};
+
+    #[test]
+    fn scaffolded_tests_are_unsolved() {
+        let source = include_str!("../template.txt");
+        assert_eq!(solved_in_tests(source), [false, false]);
+        let source = include_str!("../template_last_day.txt");
+        assert_eq!(solved_in_tests(source), [false, false]);
+    }
+
+    #[test]
+    fn detects_solved_parts() {
+        let source = "fn test_part_one() {\n    assert_eq!(result, Some(143));\n}\n\nfn test_part_two() {\n    assert_eq!(result, None);\n}\n";
+        assert_eq!(solved_in_tests(source), [true, false]);
+    }
+
+    #[test]
+    fn requires_every_test_of_a_part() {
+        let source = "fn test_part_one_a() {\n    assert_eq!(result, Some(1));\n}\nfn test_part_one_b() {\n    assert_eq!(result, None);\n}\n";
+        assert_eq!(solved_in_tests(source), [false, false]);
+    }
+
+    #[test]
+    fn detects_parts_in_manifests() {
+        let manifest = "[[example]]\nfile = \"16.txt\"\npart = 2\nanswer = 45\n";
+        assert_eq!(parts_in_manifest(manifest), [false, true]);
+        assert_eq!(parts_in_manifest("not toml ["), [false, false]);
+    }
+}
@@ -7,12 +7,17 @@ use std::{
 pub mod all;
 pub mod attempt;
 pub mod download;
+pub mod examples;
+pub mod list_years;
 pub mod new_year;
 pub mod read;
+pub mod remove_year;
 pub mod scaffold;
 pub mod set_year;
 pub mod solve;
+pub mod sync_utils;
 pub mod time;
+pub mod verify;
 
 #[derive(Debug)]
 enum WriteError {
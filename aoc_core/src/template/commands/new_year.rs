@@ -0,0 +1,276 @@
+use std::{fs, io, path::Path, process};
+
+use toml_edit::{Decor, DocumentMut, Item, Value};
+
+use crate::template::commands::set_year;
+use crate::template::repository_root;
+
+use super::{write_file, WriteError};
+
+const YEAR_NUMBER_FILES: [&str; 4] = [
+    "Cargo.toml",
+    "src/main.rs",
+    "src/solutions.rs",
+    ".cargo/config.toml",
+];
+
+pub fn handle(year: u32) {
+    let project_root = repository_root().join("year_template");
+    let new_root = repository_root().join(format!("{}", year));
+    if new_root.exists() {
+        eprintln!("{} directory already exists", year);
+        process::exit(1);
+    }
+
+    // check the manifest before creating anything, e.g. to refuse a duplicate member.
+    let Ok(original_manifest) = read_toml_file() else {
+        process::exit(1);
+    };
+    let new_manifest = match add_year_to_members(&original_manifest, year) {
+        Ok(new_manifest) => new_manifest,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    // the year is built next to its final location, so a failure never leaves a half-created year behind.
+    let staging_root = repository_root().join(format!(".new-year-{}", year));
+    if let Err(e) = create_year(year, &project_root, &staging_root) {
+        eprintln!("{e}");
+        remove_dir(&staging_root);
+        process::exit(1);
+    }
+    if let Err(e) = fs::rename(&staging_root, &new_root) {
+        eprintln!("Failed to move the new year into place: {e}");
+        remove_dir(&staging_root);
+        process::exit(1);
+    }
+
+    add_to_workspace(year, &original_manifest, &new_manifest);
+    set_year(year, &original_manifest);
+    println!("Created AOC year {} workspace module", year);
+}
+
+/// Copy the year template to `staging_root` and fill in the year number.
+fn create_year(year: u32, project_root: &Path, staging_root: &Path) -> Result<(), String> {
+    if staging_root.exists() {
+        // left over from an earlier run that was interrupted.
+        fs::remove_dir_all(staging_root)
+            .map_err(|e| format!("Failed to remove {}: {e}", staging_root.display()))?;
+    }
+    copy_dir(project_root, staging_root)
+        .map_err(|e| format!("Failed to copy the year template: {e}"))?;
+    set_year_numbers(year, staging_root)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let destination = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &destination)?;
+        } else {
+            fs::copy(entry.path(), destination)?;
+        }
+    }
+    Ok(())
+}
+
+fn set_year_numbers(year: u32, new_root: &Path) -> Result<(), String> {
+    // utility modules synced into the template refer to the crate as `advent_of_code_YEAR_NUMBER`.
+    let utils_files = fs::read_dir(new_root.join("src").join("utils"))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"));
+    let filepaths = YEAR_NUMBER_FILES
+        .iter()
+        .map(|filename| new_root.join(filename))
+        .chain(utils_files);
+
+    for filepath in filepaths {
+        let original_contents = fs::read_to_string(&filepath)
+            .map_err(|_| "Could not read from file to set year numbers".to_string())?;
+        let new_contents = original_contents.replace("YEAR_NUMBER", &year.to_string());
+
+        write_file(&filepath, new_contents.as_bytes())
+            .map_err(|_| "Could not write the year numbers".to_string())?;
+    }
+    Ok(())
+}
+
+fn add_to_workspace(year: u32, original_contents: &str, new_contents: &str) {
+    let filepath = repository_root().join("Cargo.toml");
+    if let Err(e) = write_file(&filepath, new_contents.as_bytes()) {
+        remove_dir(&repository_root().join(year.to_string()));
+        // a failed open leaves the manifest untouched.
+        if matches!(e, WriteError::Write) {
+            write_file(&filepath, original_contents.as_bytes()).unwrap();
+        }
+        process::exit(1);
+    }
+}
+
+fn set_year(year: u32, original_manifest: &str) {
+    if !set_year::set_year(year) {
+        let filepath = repository_root().join("Cargo.toml");
+        write_file(&filepath, original_manifest.as_bytes()).unwrap();
+        remove_dir(&repository_root().join(year.to_string()));
+        process::exit(1);
+    }
+}
+
+pub(super) fn read_toml_file() -> Result<String, ()> {
+    let f = fs::read_to_string(repository_root().join("Cargo.toml"));
+    if f.is_err() {
+        eprintln!("failed to read Cargo.toml");
+        return Err(());
+    }
+    Ok(f.unwrap())
+}
+
+/// Add `year` to the workspace members of a manifest and sort them.
+fn add_year_to_members(manifest: &str, year: u32) -> Result<String, String> {
+    edit_members(manifest, |names| {
+        let year = year.to_string();
+        if names.contains(&year) {
+            return Err(format!("{year} is already a member of the workspace"));
+        }
+        names.push(year);
+        names.sort();
+        Ok(())
+    })
+}
+
+/// Apply `edit` to the names of the workspace members of a manifest.
+/// Members keep the comments above them when they move, while every position keeps its indentation,
+/// so one-line and multi-line arrays both stay as they are.
+pub(super) fn edit_members(
+    manifest: &str,
+    edit: impl FnOnce(&mut Vec<String>) -> Result<(), String>,
+) -> Result<String, String> {
+    let mut manifest: DocumentMut = manifest
+        .parse()
+        .map_err(|e| format!("failed to parse Cargo.toml: {e}"))?;
+    let members = manifest
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("members"))
+        .and_then(Item::as_array_mut)
+        .ok_or("failed to find a members section of Cargo.toml")?;
+
+    let old_members: Vec<(String, Decor)> = members
+        .iter()
+        .filter_map(|member| Some((member.as_str()?.to_string(), member.decor().clone())))
+        .collect();
+    let mut names: Vec<String> = old_members.iter().map(|(name, _)| name.clone()).collect();
+    edit(&mut names)?;
+
+    let decors: Vec<&Decor> = old_members.iter().map(|(_, decor)| decor).collect();
+    let default_decor = Decor::default();
+    let new_decor = decors.last().copied().unwrap_or(&default_decor);
+    // a new member starts on its own line if the last member does.
+    let new_lines = if split_prefix(new_decor).0.is_empty() {
+        ""
+    } else {
+        "\n"
+    };
+    members.clear();
+    for (i, name) in names.into_iter().enumerate() {
+        let position = decors.get(i).copied().unwrap_or(new_decor);
+        let lines = old_members
+            .iter()
+            .find(|(old_name, _)| *old_name == name)
+            .map_or(new_lines, |(_, decor)| split_prefix(decor).0);
+        let mut member = Value::from(name);
+        *member.decor_mut() = position.clone();
+        member
+            .decor_mut()
+            .set_prefix(format!("{lines}{}", split_prefix(position).1));
+        members.push_formatted(member);
+    }
+
+    Ok(manifest.to_string())
+}
+
+/// Split the prefix of a member into the lines above it, including any comments, and its indentation.
+fn split_prefix(decor: &Decor) -> (&str, &str) {
+    let prefix = decor.prefix().and_then(|p| p.as_str()).unwrap_or("");
+    match prefix.rfind('\n') {
+        Some(end) => prefix.split_at(end + 1),
+        None => ("", prefix),
+    }
+}
+
+fn remove_dir(path: &Path) {
+    if path.exists() {
+        if let Err(e) = fs::remove_dir_all(path) {
+            eprintln!("Failed to remove {}: {e}", path.display());
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_year_to_members, create_year};
+    use crate::template::repository_root;
+
+    #[test]
+    fn adds_year_to_sorted_members() {
+        let manifest =
+            "[workspace]\n# the years\nmembers = [\"2024\", \"aoc_core\", \"year_template\"]\n";
+        assert_eq!(
+            add_year_to_members(manifest, 2022).unwrap(),
+            "[workspace]\n# the years\nmembers = [\"2022\", \"2024\", \"aoc_core\", \"year_template\"]\n"
+        );
+    }
+
+    #[test]
+    fn keeps_multi_line_members() {
+        let manifest = "[workspace]\nmembers = [\n    \"2024\",\n    \"aoc_core\",\n]\n";
+        assert_eq!(
+            add_year_to_members(manifest, 2025).unwrap(),
+            "[workspace]\nmembers = [\n    \"2024\",\n    \"2025\",\n    \"aoc_core\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn keeps_comments_with_their_members() {
+        let manifest = "[workspace]\nmembers = [\n    # solutions\n    \"2024\",\n    # shared code\n    \"aoc_core\",\n]\n";
+        assert_eq!(
+            add_year_to_members(manifest, 2022).unwrap(),
+            "[workspace]\nmembers = [\n    \"2022\",\n    # solutions\n    \"2024\",\n    # shared code\n    \"aoc_core\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn refuses_duplicate_year() {
+        let manifest = "[workspace]\nmembers = [\"2024\"]\n";
+        assert!(add_year_to_members(manifest, 2024).is_err());
+    }
+
+    #[test]
+    fn creates_year_from_template() {
+        let staging_root =
+            std::env::temp_dir().join(format!("aoc-new-year-{}", std::process::id()));
+        create_year(
+            2019,
+            &repository_root().join("year_template"),
+            &staging_root,
+        )
+        .unwrap();
+
+        let manifest = std::fs::read_to_string(staging_root.join("Cargo.toml")).unwrap();
+        let copied_nested = staging_root
+            .join("data")
+            .join("inputs")
+            .join(".keep")
+            .exists();
+        std::fs::remove_dir_all(&staging_root).unwrap();
+        assert!(manifest.contains("name = \"advent_of_code_2019\""));
+        assert!(!manifest.contains("YEAR_NUMBER"));
+        assert!(copied_nested);
+    }
+}
@@ -0,0 +1,17 @@
+use std::process;
+
+use crate::template::{aoc_cli, check_day_exit_on_fail, Day};
+
+pub fn handle(day: Day) {
+    check_day_exit_on_fail(day);
+
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    if let Err(e) = aoc_cli::read(day) {
+        eprintln!("failed to read puzzle: {e}");
+        process::exit(1);
+    };
+}
@@ -0,0 +1,117 @@
+use std::{
+    fs,
+    io::{self, Write},
+    process,
+};
+
+use crate::template::{repository_root, workspace_years};
+
+use super::{
+    new_year::{edit_members, read_toml_file},
+    set_year, write_file,
+};
+
+pub fn handle(year: u32, confirmed: bool) {
+    let Ok(original_manifest) = read_toml_file() else {
+        process::exit(1);
+    };
+    let new_manifest = match remove_year_from_members(&original_manifest, year) {
+        Ok(new_manifest) => new_manifest,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if !confirmed && !confirm(year) {
+        println!("Did not remove AOC year {}", year);
+        return;
+    }
+
+    let manifest_path = repository_root().join("Cargo.toml");
+    if write_file(&manifest_path, new_manifest.as_bytes()).is_err() {
+        write_file(&manifest_path, original_manifest.as_bytes()).unwrap();
+        process::exit(1);
+    }
+
+    let year_root = repository_root().join(year.to_string());
+    if year_root.exists() {
+        if let Err(e) = fs::remove_dir_all(&year_root) {
+            eprintln!("Failed to remove {}: {e}", year_root.display());
+            write_file(&manifest_path, original_manifest.as_bytes()).unwrap();
+            process::exit(1);
+        }
+    }
+    println!("Removed AOC year {}", year);
+
+    if set_year::configured_year() == Some(year) {
+        reset_year();
+    }
+}
+
+/// Ask on the terminal before deleting a year's solutions and data.
+fn confirm(year: u32) -> bool {
+    print!("Remove {year}/ with all of its solutions and data? [y/N] ");
+    io::stdout().flush().unwrap();
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Point the repository at the most recent remaining year.
+fn reset_year() {
+    match workspace_years().last() {
+        Some(&latest) => {
+            if set_year::set_year(latest) {
+                println!("Set repository to AOC year {}", latest);
+            }
+        }
+        None => eprintln!(
+            "No years left in the workspace, create one with `cargo new-year <year>` before running other commands."
+        ),
+    }
+}
+
+fn remove_year_from_members(manifest: &str, year: u32) -> Result<String, String> {
+    edit_members(manifest, |names| {
+        let year = year.to_string();
+        if !names.contains(&year) {
+            return Err(format!("{year} is not a member of the workspace"));
+        }
+        names.retain(|name| *name != year);
+        Ok(())
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::remove_year_from_members;
+
+    #[test]
+    fn removes_year_from_members() {
+        let manifest = "[workspace]\nmembers = [\"2022\", \"2024\", \"aoc_core\"]\n";
+        assert_eq!(
+            remove_year_from_members(manifest, 2022).unwrap(),
+            "[workspace]\nmembers = [\"2024\", \"aoc_core\"]\n"
+        );
+    }
+
+    #[test]
+    fn keeps_multi_line_members() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"2022\",\n    \"2024\",\n    \"aoc_core\",\n]\n";
+        assert_eq!(
+            remove_year_from_members(manifest, 2024).unwrap(),
+            "[workspace]\nmembers = [\n    \"2022\",\n    \"aoc_core\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn refuses_unknown_year() {
+        let manifest = "[workspace]\nmembers = [\"2024\"]\n";
+        assert!(remove_year_from_members(manifest, 2019).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::aoc_cli::decode_html;
+use crate::template::{check_day_exit_on_fail, get_year_exit_on_fail, repository_root, Day};
+
+const MODULE_TEMPLATE: &str = include_str!("../template.txt");
+/// The last day of an event only has part one.
+const LAST_DAY_MODULE_TEMPLATE: &str = include_str!("../template_last_day.txt");
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
+    let mut file = OpenOptions::new();
+    if overwrite {
+        file.create(true);
+    } else {
+        file.create_new(true);
+    }
+    file.truncate(true).write(true).open(path)
+}
+
+/// Create an empty file unless it already exists, e.g. because the input was downloaded. Returns whether it was created.
+fn create_empty_file(path: &Path) -> Result<bool, io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn handle(day: Day, overwrite: bool, template_name: Option<&str>) {
+    check_day_exit_on_fail(day);
+    let year = get_year_exit_on_fail();
+    let input_path = format!("{year}/data/inputs/{day}.txt");
+    let example_path = format!("{year}/data/examples/{day}.txt");
+    let module_path = format!("{year}/src/bin/{day}.rs");
+
+    let root = repository_root();
+    let template = match template_name {
+        Some(name) => {
+            let template = read_named_template(year, name).and_then(|template| {
+                if day.parts(year) == 1 {
+                    only_part_one(&template).map_err(|e| {
+                        format!("Template \"{name}\" cannot be used for the last day: {e}")
+                    })
+                } else {
+                    Ok(template)
+                }
+            });
+            template.unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            })
+        }
+        None if day.parts(year) == 1 => LAST_DAY_MODULE_TEMPLATE.to_string(),
+        None => MODULE_TEMPLATE.to_string(),
+    };
+
+    let mut file = match safe_create_file(&root.join(&module_path), overwrite) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(fill_in_placeholders(&template, year, day).as_bytes()) {
+        Ok(()) => {
+            println!("Created module file \"{}\"", &module_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_empty_file(&root.join(&input_path)) {
+        Ok(true) => {
+            println!("Created empty input file \"{}\"", &input_path);
+        }
+        Ok(false) => {
+            println!("Kept the existing input file \"{}\"", &input_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create input file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_empty_file(&root.join(&example_path)) {
+        Ok(true) => {
+            println!("Created empty example file \"{}\"", &example_path);
+        }
+        Ok(false) => {
+            println!("Kept the existing example file \"{}\"", &example_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day}` to run your solution.");
+}
+
+/// Directories with named templates, the year's own before the ones copied into new years.
+fn template_dirs(year: u32) -> [PathBuf; 2] {
+    let root = repository_root();
+    [
+        root.join(year.to_string()).join("templates"),
+        root.join("year_template").join("templates"),
+    ]
+}
+
+fn read_named_template(year: u32, name: &str) -> Result<String, String> {
+    let dirs = template_dirs(year);
+    if let Some(template) = dirs
+        .iter()
+        .find_map(|dir| fs::read_to_string(dir.join(format!("{name}.txt"))).ok())
+    {
+        return Ok(template);
+    }
+
+    let mut names: Vec<String> = dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+    names.dedup();
+    Err(format!(
+        "Unknown template \"{name}\". Available templates: {}",
+        names.join(", ")
+    ))
+}
+
+/// Replace the placeholders of a module template.
+fn fill_in_placeholders(template: &str, year: u32, day: Day) -> String {
+    let puzzle_path = repository_root()
+        .join(year.to_string())
+        .join("data")
+        .join("puzzles")
+        .join(format!("{day}.md"));
+    let title = fs::read_to_string(puzzle_path)
+        .ok()
+        .and_then(|puzzle| puzzle_title(&puzzle))
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    template
+        .replace("YEAR_NUMBER", &year.to_string())
+        .replace("DAY_NUMBER", &day.into_inner().to_string())
+        .replace("PUZZLE_TITLE", &title)
+}
+
+/// The title of a downloaded puzzle, e.g. "Day 1: Historian Hysteria" from `<h2>--- Day 1: Historian Hysteria ---</h2>`.
+fn puzzle_title(puzzle: &str) -> Option<String> {
+    let start = puzzle.find("<h2>")? + "<h2>".len();
+    let end = start + puzzle[start..].find("</h2>")?;
+    let title = decode_html(&puzzle[start..end]);
+    let title = title.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Remove part two and its test from a template, for the last day of an event.
+/// Fails if the template is laid out differently than the built-in ones, instead of leaving part two in place.
+fn only_part_one(template: &str) -> Result<String, String> {
+    if !template.contains("solution!(DAY_NUMBER") {
+        return Err("`solution!(DAY_NUMBER` not found.".into());
+    }
+    let module = template.replacen("solution!(DAY_NUMBER", "solution!(DAY_NUMBER, 1", 1);
+    let module = remove_item(&module, "pub fn part_two")?;
+    let module = remove_item(&module, "fn test_part_two")?;
+
+    if module.contains("part_two") {
+        return Err("`part_two` is still used after removing it.".into());
+    }
+    Ok(module)
+}
+
+/// Remove the item whose first line starts with `signature`, its attributes and an empty line next to it.
+/// The item ends at the first line that only holds a `}` as indented as the signature.
+/// Every line in between has to be indented deeper, so nothing after the item is removed.
+fn remove_item(module: &str, signature: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = module.split('\n').collect();
+    let first = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with(signature))
+        .ok_or_else(|| format!("`{signature}` not found."))?;
+    let indent = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+    let closing = format!("{indent}}}");
+
+    let len = lines[first..]
+        .iter()
+        .position(|line| *line == closing)
+        .ok_or_else(|| format!("`{signature}` does not end with a `}}` on its own line."))?;
+    let mut last = first + len;
+    // the end of a signature that spans multiple lines is as indented as its start.
+    let is_inside = |line: &&str| {
+        line.is_empty()
+            || line
+                .strip_prefix(indent)
+                .is_some_and(|rest| rest.starts_with(char::is_whitespace) || rest.starts_with(')'))
+    };
+    if !lines[first + 1..last].iter().all(is_inside) {
+        return Err(format!(
+            "`{signature}` does not end with a `}}` as indented as its first line."
+        ));
+    }
+
+    let mut start = first;
+    while start > 0 && lines[start - 1].trim_start().starts_with("#[") {
+        start -= 1;
+    }
+    if start > 0 && lines[start - 1].is_empty() {
+        start -= 1;
+    } else if lines.get(last + 1).is_some_and(|line| line.is_empty()) {
+        last += 1;
+    }
+
+    lines.drain(start..=last);
+    Ok(lines.join("\n"))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{only_part_one, puzzle_title};
+
+    #[test]
+    fn derives_last_day_template() {
+        assert_eq!(
+            only_part_one(include_str!("../template.txt")),
+            Ok(include_str!("../template_last_day.txt").to_string())
+        );
+    }
+
+    #[test]
+    fn removes_part_two_of_named_templates() {
+        let template = include_str!("../../../../year_template/templates/grid.txt");
+        let module = only_part_one(template).unwrap();
+        assert!(module.contains("solution!(DAY_NUMBER, 1, parse: parse_input);"));
+        assert!(!module.contains("part_two"));
+        assert!(module.contains("fn test_part_one()"));
+
+        for template in [
+            include_str!("../../../../year_template/templates/blank.txt"),
+            include_str!("../../../../year_template/templates/graph.txt"),
+            include_str!("../../../../year_template/templates/lines.txt"),
+        ] {
+            assert!(only_part_one(template).is_ok());
+        }
+    }
+
+    #[test]
+    fn removes_part_two_with_a_multi_line_signature() {
+        let template = include_str!("../template.txt").replace(
+            "pub fn part_two(input: &str) -> Option<u64> {",
+            "pub fn part_two(\n    input: &str,\n) -> Option<u64> {",
+        );
+        assert_eq!(
+            only_part_one(&template),
+            Ok(include_str!("../template_last_day.txt").to_string())
+        );
+    }
+
+    #[test]
+    fn fails_for_templates_laid_out_differently() {
+        let template = include_str!("../template.txt");
+        let compact = template.replace(
+            "    None\n}\n\nfn parse_input",
+            "    None }\n\nfn parse_input",
+        );
+        assert!(only_part_one(&compact).is_err());
+        let tabs = template.replace(
+            "    #[test]\n    fn test_part_two",
+            "\t#[test]\n\tfn test_part_two",
+        );
+        assert!(only_part_one(&tabs).is_err());
+        assert!(only_part_one("fn main() {}\n").is_err());
+    }
+
+    #[test]
+    fn reads_puzzle_titles() {
+        let puzzle = "<article><h2>--- Day 4: Ceres &amp; Search ---</h2><p>...</p></article>";
+        assert_eq!(puzzle_title(puzzle), Some("Day 4: Ceres & Search".into()));
+        assert_eq!(puzzle_title("<p>no title</p>"), None);
+    }
+}
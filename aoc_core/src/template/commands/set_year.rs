@@ -0,0 +1,111 @@
+use std::{
+    fs::{self},
+    path::PathBuf,
+    process,
+};
+
+use toml_edit::{table, value, DocumentMut, Item, Value};
+
+use super::write_file;
+
+pub fn handle(year: u32) {
+    if !set_year(year) {
+        process::exit(1);
+    }
+    println!("Set repository to AOC year {}", year);
+}
+
+pub fn set_year(year: u32) -> bool {
+    let config_path = get_config_path();
+    let config_contents = read_config(&config_path);
+    if config_contents.is_err() {
+        return false;
+    }
+    let new_contents = match set_year_in_config(&config_contents.unwrap(), year) {
+        Ok(new_contents) => new_contents,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    match write_file(&config_path, new_contents.as_bytes()) {
+        Ok(_) => true,
+        Err(_) => {
+            eprintln!("failed to write new year to the config file");
+            false
+        }
+    }
+}
+
+/// The year set in the repository's `.cargo/config.toml`, regardless of any override for the running process.
+pub fn configured_year() -> Option<u32> {
+    let config: DocumentMut = read_config(&get_config_path()).ok()?.parse().ok()?;
+    config.get("env")?.get("AOC_YEAR")?.as_str()?.parse().ok()
+}
+
+/// Set `AOC_YEAR` in the `[env]` table of a cargo config. Everything else, including comments, is left as is.
+fn set_year_in_config(config: &str, year: u32) -> Result<String, String> {
+    let mut config: DocumentMut = config
+        .parse()
+        .map_err(|e| format!("failed to parse .cargo/config.toml: {e}"))?;
+    let env = config
+        .entry("env")
+        .or_insert_with(table)
+        .as_table_like_mut()
+        .ok_or("`env` in .cargo/config.toml is not a table")?;
+
+    match env.get_mut("AOC_YEAR").and_then(Item::as_value_mut) {
+        Some(current) => {
+            let decor = current.decor().clone();
+            *current = Value::from(year.to_string());
+            *current.decor_mut() = decor;
+        }
+        None => {
+            env.insert("AOC_YEAR", value(year.to_string()));
+        }
+    }
+    Ok(config.to_string())
+}
+
+fn get_config_path() -> PathBuf {
+    crate::template::repository_root()
+        .join(".cargo")
+        .join("config.toml")
+}
+
+fn read_config(filepath: &PathBuf) -> Result<String, ()> {
+    let f = fs::read_to_string(filepath);
+    if f.is_err() {
+        eprintln!("failed to read Cargo.toml");
+        return Err(());
+    }
+    Ok(f.unwrap())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::set_year_in_config;
+
+    #[test]
+    fn replaces_year_and_keeps_formatting() {
+        let config = "[alias]\n# mentions AOC_YEAR in a comment\nsolve = \"run -- solve\"\n\n[env]\nAOC_YEAR = \"2024\" # current year\n";
+        assert_eq!(
+            set_year_in_config(config, 2022).unwrap(),
+            config.replace("\"2024\"", "\"2022\"")
+        );
+    }
+
+    #[test]
+    fn adds_missing_env_table() {
+        assert_eq!(
+            set_year_in_config("[alias]\nsolve = \"run -- solve\"\n", 2022).unwrap(),
+            "[alias]\nsolve = \"run -- solve\"\n\n[env]\nAOC_YEAR = \"2022\"\n"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(set_year_in_config("[env\n", 2022).is_err());
+    }
+}
@@ -0,0 +1,419 @@
+//! Compares and copies the year-specific utility modules in `<year>/src/utils/` and `year_template/src/utils/`.
+//! Utilities shared by every year live in `aoc_core` and are not affected.
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::{repository_root, workspace_years, ANSI_BOLD, ANSI_RESET};
+
+/// Placeholder for the year in `year_template/`, replaced when a new year is created.
+const TEMPLATE_YEAR: &str = "YEAR_NUMBER";
+
+/// A crate whose utility modules take part in the sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Template,
+    Year(u32),
+}
+
+impl Location {
+    /// The suffix of the crate's name, e.g. `2024` for `advent_of_code_2024`.
+    fn crate_suffix(self) -> String {
+        match self {
+            Location::Template => TEMPLATE_YEAR.to_string(),
+            Location::Year(year) => year.to_string(),
+        }
+    }
+
+    fn utils_dir(self) -> PathBuf {
+        let dir = match self {
+            Location::Template => "year_template".to_string(),
+            Location::Year(year) => year.to_string(),
+        };
+        repository_root().join(dir).join("src").join("utils")
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::Template => write!(f, "template"),
+            Location::Year(year) => write!(f, "{year}"),
+        }
+    }
+}
+
+/// Without `from`, print which functions of the utility modules differ between the years and the template.
+/// With `from`, copy that year's `modules` (all of them if empty) into the template and every other year.
+pub fn handle(from: Option<u32>, modules: &[String]) {
+    let locations: Vec<Location> = std::iter::once(Location::Template)
+        .chain(workspace_years().into_iter().map(Location::Year))
+        .collect();
+
+    match from {
+        Some(year) if !locations.contains(&Location::Year(year)) => {
+            eprintln!("{year} is not a member of the workspace.");
+            process::exit(1);
+        }
+        Some(year) => push(Location::Year(year), modules, &locations),
+        None => print_differences(&locations),
+    }
+}
+
+/// Read the utility modules of a location, keyed by file name.
+/// `mod.rs` only declares the modules and re-exports `aoc_core`, so it is skipped.
+fn read_modules(location: Location) -> BTreeMap<String, String> {
+    let Ok(entries) = fs::read_dir(location.utils_dir()) else {
+        return BTreeMap::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?.to_string();
+            (name != "mod.rs")
+                .then(|| fs::read_to_string(&path).ok())
+                .flatten()
+                .map(|source| (name, source))
+        })
+        .collect()
+}
+
+fn print_differences(locations: &[Location]) {
+    // module name -> location -> source, with crate paths rewritten to the template's.
+    let mut modules: BTreeMap<String, Vec<(Location, String)>> = BTreeMap::new();
+    for &location in locations {
+        for (name, source) in read_modules(location) {
+            let source = rewrite_crate_paths(&source, &location.crate_suffix(), TEMPLATE_YEAR);
+            modules.entry(name).or_default().push((location, source));
+        }
+    }
+
+    if modules.is_empty() {
+        println!("No year has utility modules of its own in `src/utils/`.");
+        return;
+    }
+
+    for (name, sources) in &modules {
+        println!("{ANSI_BOLD}{name}{ANSI_RESET}");
+        let missing: Vec<String> = locations
+            .iter()
+            .filter(|location| !sources.iter().any(|(l, _)| l == *location))
+            .map(ToString::to_string)
+            .collect();
+        if !missing.is_empty() {
+            println!("  missing in: {}", missing.join(", "));
+        }
+
+        let lines = function_differences(sources);
+        if lines.is_empty() && sources.len() > 1 {
+            println!(
+                "  identical in: {}",
+                join_locations(sources.iter().map(|(l, _)| *l))
+            );
+        }
+        lines.iter().for_each(|line| println!("  {line}"));
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo sync-utils --from <year> [module...]` to copy a year's modules everywhere else.");
+}
+
+/// Describe every function that is not the same in all given versions of a module.
+/// Each line lists the groups of locations that share a version, separated by `|`.
+fn function_differences(sources: &[(Location, String)]) -> Vec<String> {
+    // function name -> function text -> locations with that text.
+    let mut functions: BTreeMap<String, Vec<(String, Vec<Location>)>> = BTreeMap::new();
+    for (location, source) in sources {
+        for (name, text) in extract_functions(source) {
+            let variants = functions.entry(name).or_default();
+            match variants.iter_mut().find(|(t, _)| *t == text) {
+                Some((_, locations)) => locations.push(*location),
+                None => variants.push((text, vec![*location])),
+            }
+        }
+    }
+
+    functions
+        .into_iter()
+        .filter_map(|(name, variants)| {
+            let found: usize = variants.iter().map(|(_, l)| l.len()).sum();
+            if variants.len() == 1 && found == sources.len() {
+                return None;
+            }
+            let mut groups: Vec<String> = variants
+                .into_iter()
+                .map(|(_, locations)| join_locations(locations.into_iter()))
+                .collect();
+            if found < sources.len() {
+                groups.push("(missing elsewhere)".into());
+            }
+            Some(format!("fn {name}: {}", groups.join(" | ")))
+        })
+        .collect()
+}
+
+fn join_locations(locations: impl Iterator<Item = Location>) -> String {
+    locations
+        .map(|location| location.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn push(from: Location, modules: &[String], locations: &[Location]) {
+    let available = read_modules(from);
+    let selected: Vec<(&String, &String)> = if modules.is_empty() {
+        available.iter().collect()
+    } else {
+        modules
+            .iter()
+            .map(|module| {
+                let name = format!("{}.rs", module.trim_end_matches(".rs"));
+                available.get_key_value(&name).unwrap_or_else(|| {
+                    eprintln!("{from} has no utility module `{name}`.");
+                    process::exit(1);
+                })
+            })
+            .collect()
+    };
+
+    if selected.is_empty() {
+        println!("{from} has no utility modules of its own in `src/utils/`.");
+        return;
+    }
+
+    for &target in locations.iter().filter(|location| **location != from) {
+        let dir = target.utils_dir();
+        for (name, source) in &selected {
+            let source = rewrite_crate_paths(source, &from.crate_suffix(), &target.crate_suffix());
+            if let Err(e) = fs::write(dir.join(name), source) {
+                eprintln!("Failed to write {}: {e}", dir.join(name).display());
+                process::exit(1);
+            }
+            declare_module(&dir, name.trim_end_matches(".rs"));
+        }
+        let names: Vec<&str> = selected.iter().map(|(name, _)| name.as_str()).collect();
+        println!("Updated {target}: {}", names.join(", "));
+    }
+}
+
+/// Add `pub mod <module>;` to the `mod.rs` in `dir` unless the module is already declared.
+fn declare_module(dir: &Path, module: &str) {
+    let path = dir.join("mod.rs");
+    let mut contents = fs::read_to_string(&path).unwrap_or_default();
+    let declared = contents.lines().any(|line| {
+        let line = line.trim();
+        line == format!("pub mod {module};") || line == format!("mod {module};")
+    });
+    if declared {
+        return;
+    }
+
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!("pub mod {module};\n"));
+    if let Err(e) = fs::write(&path, contents) {
+        eprintln!("Failed to write {}: {e}", path.display());
+        process::exit(1);
+    }
+}
+
+/// Point paths into the crate `advent_of_code_<from>` at `advent_of_code_<to>`.
+fn rewrite_crate_paths(source: &str, from: &str, to: &str) -> String {
+    source.replace(
+        &format!("advent_of_code_{from}"),
+        &format!("advent_of_code_{to}"),
+    )
+}
+
+/// Split a module's source into its functions, keyed by name.
+/// Methods are prefixed with the type of their `impl` block, e.g. `Grid::get`.
+fn extract_functions(source: &str) -> Vec<(String, String)> {
+    let mut functions = vec![];
+    let mut impl_type: Option<String> = None;
+    // name, text so far and brace depth of the function being read; the depth is `None` until its body opens.
+    let mut current: Option<(String, String, Option<i32>)> = None;
+
+    for line in source.lines() {
+        let (name, text, depth) = match current.take() {
+            Some((name, mut text, depth)) => {
+                text.push('\n');
+                text.push_str(line);
+                (name, text, depth)
+            }
+            None => {
+                if line.starts_with("impl") {
+                    impl_type = Some(impl_target(line));
+                } else if line.starts_with('}') {
+                    impl_type = None;
+                }
+
+                let Some(name) = function_name(line) else {
+                    continue;
+                };
+                let name = match &impl_type {
+                    Some(ty) if line.starts_with(char::is_whitespace) => format!("{ty}::{name}"),
+                    _ => name,
+                };
+                (name, line.to_string(), None)
+            }
+        };
+
+        let depth = match depth {
+            Some(depth) => Some(depth + brace_balance(line)),
+            None if line.contains('{') => Some(brace_balance(line)),
+            None => None,
+        };
+        match depth {
+            Some(depth) if depth <= 0 => functions.push((name, text)),
+            // a declaration without a body, e.g. in a trait.
+            None if line.trim_end().ends_with(';') => functions.push((name, text)),
+            _ => current = Some((name, text, depth)),
+        }
+    }
+
+    functions
+}
+
+/// The name of the function declared on `line`, if any.
+fn function_name(line: &str) -> Option<String> {
+    let line = line.trim_start();
+    if line.starts_with("//") {
+        return None;
+    }
+    let rest = line
+        .split_once("fn ")
+        .filter(|(prefix, _)| {
+            prefix
+                .split_whitespace()
+                .all(|word| word.starts_with("pub") || ["const", "unsafe", "async"].contains(&word))
+        })?
+        .1;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// The type an `impl` line refers to, without generics. E.g. `Grid` for `impl<T: GridCell> Grid<T> {`.
+fn impl_target(line: &str) -> String {
+    let header = line.trim_end_matches('{').trim();
+    let header = header.rsplit_once(" for ").map_or(header, |(_, ty)| ty);
+    let header = header.strip_prefix("impl").unwrap_or(header);
+    // skip the impl's own generic parameters, e.g. `<T: GridCell>`.
+    let header = if header.starts_with('<') {
+        let mut depth = 0;
+        let end = header
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map_or(header.len(), |(i, _)| i + 1);
+        &header[end..]
+    } else {
+        header
+    };
+    header
+        .trim()
+        .split(|c: char| c == '<' || c.is_whitespace())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Opening minus closing braces on a line, ignoring braces in string and character literals and comments.
+fn brace_balance(line: &str) -> i32 {
+    let mut balance = 0;
+    let mut in_string = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '/' if !in_string && chars.peek() == Some(&'/') => break,
+            '\'' if !in_string => {
+                // skip character literals such as '{', but not lifetimes such as 'a.
+                let mut lookahead = chars.clone();
+                if let (Some(_), Some('\'')) = (lookahead.next(), lookahead.next()) {
+                    chars.next();
+                    chars.next();
+                }
+            }
+            '{' if !in_string => balance += 1,
+            '}' if !in_string => balance -= 1,
+            _ => {}
+        }
+    }
+    balance
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_functions, function_differences, rewrite_crate_paths, Location};
+
+    const MODULE: &str = "use advent_of_code_2024::utils::grid::Grid;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.split('{').collect()
+}
+
+impl<T: Clone> Wrapper<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    fn get(&self) -> &T {
+        &self.value
+    }
+}
+";
+
+    #[test]
+    fn extracts_functions() {
+        let functions = extract_functions(MODULE);
+        let names: Vec<&str> = functions.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["parse", "Wrapper::new", "Wrapper::get"]);
+        assert_eq!(
+            functions[1].1,
+            "    pub fn new(value: T) -> Self {\n        Self { value }\n    }"
+        );
+    }
+
+    #[test]
+    fn rewrites_crate_paths() {
+        assert_eq!(
+            rewrite_crate_paths(MODULE, "2024", "YEAR_NUMBER")
+                .lines()
+                .next(),
+            Some("use advent_of_code_YEAR_NUMBER::utils::grid::Grid;")
+        );
+    }
+
+    #[test]
+    fn lists_differing_functions() {
+        let changed = MODULE.replace("&self.value", "&self.value.clone()");
+        let sources = vec![
+            (Location::Template, MODULE.to_string()),
+            (Location::Year(2022), MODULE.to_string()),
+            (Location::Year(2024), changed),
+        ];
+        assert_eq!(
+            function_differences(&sources),
+            vec!["fn Wrapper::get: template, 2022 | 2024"]
+        );
+    }
+}
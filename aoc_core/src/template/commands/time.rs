@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
+
+use crate::template::run_multi::{child_commands, has_failures, run_multi, to_timings};
+use crate::template::runner::{OutputFormat, Solution};
+use crate::template::timings::Timings;
+use crate::template::{
+    all_days, check_day_exit_on_fail, get_year_exit_on_fail, readme_benchmarks, Day, ANSI_BOLD,
+    ANSI_RESET,
+};
+
+/// Slowdown (in percent) relative to the last stored run above which a part is flagged as a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 20.0;
+
+/// Change of a part's run time relative to the last stored run.
+#[derive(Debug, PartialEq)]
+struct Delta {
+    day: Day,
+    part: u8,
+    previous_nanos: f64,
+    current_nanos: f64,
+}
+
+impl Delta {
+    fn percent(&self) -> f64 {
+        (self.current_nanos - self.previous_nanos) / self.previous_nanos * 100.0
+    }
+
+    fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    show_stats: bool,
+    threshold: Option<f64>,
+    timeout: Option<u64>,
+    format: OutputFormat,
+) {
+    if let Some(day) = day {
+        check_day_exit_on_fail(day);
+    }
+    let args = args(day, run_all, store, show_stats, threshold, timeout, format);
+    child_commands::run_solutions_exit_on_fail(&args, true);
+}
+
+/// Arguments that make a year's `solutions` binary bench its days.
+pub fn args(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    show_stats: bool,
+    threshold: Option<f64>,
+    timeout: Option<u64>,
+    format: OutputFormat,
+) -> Vec<String> {
+    let mut args = vec!["time".to_string()];
+    if let Some(day) = day {
+        args.push(day.to_string());
+    }
+    if run_all {
+        args.push("--all".into());
+    }
+    if store {
+        args.push("--store".into());
+    }
+    if show_stats {
+        args.push("--stats".into());
+    }
+    if let Some(threshold) = threshold {
+        args.extend(["--threshold".into(), threshold.to_string()]);
+    }
+    if let Some(timeout) = timeout {
+        args.extend(["--timeout".into(), timeout.to_string()]);
+    }
+    args.extend(format.to_args());
+    args
+}
+
+/// Bench registered solutions in-process. Called by the year's `solutions` binary.
+/// Exits with a non-zero status if a day panicked, timed out or regressed.
+pub fn run(
+    solutions: &'static [Solution],
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    threshold: f64,
+    timeout: Option<Duration>,
+    format: OutputFormat,
+) {
+    // storing after a failed read would overwrite the history of the timings file.
+    let stored_timings = Timings::read_from_file().unwrap_or_else(|e| {
+        if store {
+            eprintln!("Failed to read the stored timings, not storing: {e}");
+            process::exit(1);
+        }
+        eprintln!("Ignoring the stored timings: {e}");
+        Timings::default()
+    });
+    let year = get_year_exit_on_fail();
+
+    let days_to_run = day.map_or_else(
+        || {
+            if run_all {
+                all_days().collect()
+            } else {
+                // when the `--all` flag is not set, filter out days that are fully benched.
+                all_days()
+                    .filter(|day| !stored_timings.is_day_complete(*day, year))
+                    .collect()
+            }
+        },
+        |day| HashSet::from([day]),
+    );
+
+    let results = run_multi(solutions, &days_to_run, true, 1, timeout, format);
+    let timings = to_timings(&results);
+
+    let deltas = compare(&stored_timings, &timings);
+    if !deltas.is_empty() {
+        print_deltas(&deltas, threshold, format);
+    }
+
+    if store {
+        let merged_timings = stored_timings.merge(&timings);
+        merged_timings.store_file().unwrap();
+
+        match readme_benchmarks::update() {
+            // keep stdout parseable in JSON mode.
+            Ok(()) if format == OutputFormat::Json => {
+                eprintln!("Stored updated benchmarks.");
+            }
+            Ok(()) => {
+                println!();
+                println!("Stored updated benchmarks.");
+            }
+            Err(readme_benchmarks::Error::Timings(e)) => {
+                eprintln!("Failed to update the benchmarks in the README: {e}");
+            }
+            Err(_) => {
+                eprintln!("Failed to store updated benchmarks.");
+            }
+        }
+    }
+
+    if has_failures(&results) || deltas.iter().any(|delta| delta.is_regression(threshold)) {
+        process::exit(1);
+    }
+}
+
+/// Compare the new results with the most recent stored run of each part.
+fn compare(stored: &Timings, new: &Timings) -> Vec<Delta> {
+    new.history
+        .iter()
+        .filter_map(|record| {
+            let previous = stored.last_record(record.day, record.part)?;
+            Some(Delta {
+                day: record.day,
+                part: record.part,
+                previous_nanos: previous.nanos,
+                current_nanos: record.nanos,
+            })
+        })
+        .collect()
+}
+
+fn print_deltas(deltas: &[Delta], threshold: f64, format: OutputFormat) {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = |nanos: f64| Duration::from_nanos(nanos as u64);
+
+    let lines: Vec<String> = deltas
+        .iter()
+        .map(|delta| {
+            let flag = if delta.is_regression(threshold) {
+                " ✖ regression"
+            } else {
+                ""
+            };
+            format!(
+                "Day {} part {}: {:.1?} → {:.1?} ({:+.1}%){flag}",
+                delta.day,
+                delta.part,
+                duration(delta.previous_nanos),
+                duration(delta.current_nanos),
+                delta.percent()
+            )
+        })
+        .collect();
+
+    // keep stdout parseable in JSON mode.
+    if format == OutputFormat::Json {
+        lines.iter().for_each(|line| eprintln!("{line}"));
+    } else {
+        println!();
+        println!("{ANSI_BOLD}Changes since the last stored run:{ANSI_RESET}");
+        lines.iter().for_each(|line| println!("{line}"));
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, Delta};
+    use crate::day;
+    use crate::template::timings::{TimingRecord, Timings};
+    use crate::template::Day;
+
+    fn record(day: Day, part: u8, nanos: f64, timestamp: u64) -> TimingRecord {
+        TimingRecord {
+            day,
+            part,
+            nanos,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn compares_with_last_stored_run() {
+        let stored = Timings {
+            data: vec![],
+            history: vec![record(day!(1), 1, 100.0, 1), record(day!(1), 1, 200.0, 2)],
+        };
+        let new = Timings {
+            data: vec![],
+            history: vec![record(day!(1), 1, 300.0, 3), record(day!(2), 1, 50.0, 3)],
+        };
+        assert_eq!(
+            compare(&stored, &new),
+            vec![Delta {
+                day: day!(1),
+                part: 1,
+                previous_nanos: 200.0,
+                current_nanos: 300.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn flags_regressions_above_threshold() {
+        let delta = Delta {
+            day: day!(1),
+            part: 1,
+            previous_nanos: 100.0,
+            current_nanos: 130.0,
+        };
+        assert_eq!(delta.is_regression(20.0), true);
+        assert_eq!(delta.is_regression(50.0), false);
+    }
+}
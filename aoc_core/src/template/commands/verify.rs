@@ -0,0 +1,152 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::answers::{puzzle_answers, Answers};
+use crate::template::run_multi::{child_commands, run_multi, uncompiled_days, DayStatus};
+use crate::template::runner::{OutputFormat, Solution};
+use crate::template::{get_year_exit_on_fail, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Outcome of comparing one part's answer to the recorded correct answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    /// No correct answer is known for this part.
+    Unverified,
+}
+
+struct Row {
+    day: Day,
+    part: u8,
+    expected: Option<String>,
+    actual: Option<String>,
+    status: Status,
+}
+
+pub fn handle(is_release: bool, timeout: Option<u64>) {
+    let mut args = vec!["verify".to_string()];
+    if let Some(timeout) = timeout {
+        args.extend(["--timeout".into(), timeout.to_string()]);
+    }
+    child_commands::run_solutions_exit_on_fail(&args, is_release);
+}
+
+/// Run every registered solution in-process and compare the answers. Called by the year's `solutions` binary.
+/// Days that panicked or timed out fail every part with a known answer.
+/// Exits with 1 if any part fails and with 2 if no part fails but some could not be verified.
+pub fn run(solutions: &'static [Solution], timeout: Option<Duration>) {
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read the answer ledger: {e}");
+        process::exit(1);
+    });
+    let year = get_year_exit_on_fail();
+    let days = solutions
+        .iter()
+        .map(|s| s.day)
+        .chain(uncompiled_days())
+        .collect();
+    let results = run_multi(solutions, &days, false, 1, timeout, OutputFormat::Text);
+    println!();
+
+    let mut rows: Vec<Row> = vec![];
+    for result in results
+        .iter()
+        .filter(|r| r.status != DayStatus::MissingInput)
+    {
+        let solved = puzzle_answers(result.day);
+        for part in 1..=result.day.parts(year) {
+            let expected = answers
+                .get(result.day, part)
+                .and_then(|a| a.accepted.clone())
+                .or_else(|| solved.get(usize::from(part) - 1).cloned());
+            let actual = result
+                .parts
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.clone());
+            let status = compare(expected.as_deref(), actual.as_deref());
+            rows.push(Row {
+                day: result.day,
+                part,
+                expected,
+                actual,
+                status,
+            });
+        }
+    }
+
+    print_table(&rows);
+
+    if rows.iter().any(|row| row.status == Status::Fail) {
+        process::exit(1);
+    }
+    if rows.iter().any(|row| row.status == Status::Unverified) {
+        eprintln!(
+            "Parts without a known answer were not verified. Answers are taken from `data/answers.json` and from the puzzles downloaded with `cargo read <day>` after solving them."
+        );
+        process::exit(2);
+    }
+}
+
+fn compare(expected: Option<&str>, actual: Option<&str>) -> Status {
+    match (expected, actual) {
+        (None, _) => Status::Unverified,
+        (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+        _ => Status::Fail,
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let display = |answer: &Option<String>| match answer {
+        Some(answer) if answer.contains('\n') => "(multi-line)".to_string(),
+        Some(answer) => answer.clone(),
+        None => "-".to_string(),
+    };
+    let width = rows
+        .iter()
+        .flat_map(|row| [display(&row.expected).len(), display(&row.actual).len()])
+        .max()
+        .unwrap_or(0)
+        .max("Expected".len());
+
+    println!(
+        "{ANSI_BOLD}Day  Part  {:<width$}  {:<width$}  Status{ANSI_RESET}",
+        "Expected", "Actual"
+    );
+    for row in rows {
+        let status = match row.status {
+            Status::Pass => "✔ pass",
+            Status::Fail => "✖ FAIL",
+            Status::Unverified => "? unverified",
+        };
+        println!(
+            "{}   {}     {:<width$}  {:<width$}  {status}",
+            row.day,
+            row.part,
+            display(&row.expected),
+            display(&row.actual),
+        );
+    }
+
+    let count = |status: Status| rows.iter().filter(|row| row.status == status).count();
+    println!(
+        "\n{ANSI_BOLD}{} passed, {} failed, {} unverified{ANSI_RESET}",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Unverified)
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, Status};
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(compare(Some("42"), Some("42")), Status::Pass);
+        assert_eq!(compare(Some("42"), Some("43")), Status::Fail);
+        assert_eq!(compare(Some("42"), None), Status::Fail);
+        assert_eq!(compare(None, Some("42")), Status::Unverified);
+        assert_eq!(compare(None, None), Status::Unverified);
+    }
+}
@@ -1,6 +1,13 @@
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Display;
+use std::fs;
 use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+use toml_edit::DocumentMut;
+
+use crate::template::{get_year, repository_root};
 
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
@@ -41,15 +48,30 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Whether the event of `year` has a puzzle on this day.
+    pub fn is_in_event(self, year: u32) -> bool {
+        self.0 <= event_days(year)
+    }
+
+    /// Number of parts of the puzzle on this day. The last day of an event only has one.
+    pub fn parts(self, year: u32) -> u8 {
+        if self.0 == event_days(year) {
+            1
+        } else {
+            2
+        }
+    }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this december's event, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        let event_days = event_days(u32::try_from(today.year()).ok()?);
+        if today.month() == 12 && today.day() <= u32::from(event_days) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -100,20 +122,67 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// Number of puzzles of the event of `year`.
+///
+/// This is 25 up to 2024 and 12 from 2025 on, unless `days` is set in the
+/// `[package.metadata.aoc]` table of the year's `Cargo.toml`.
+/// The manifest is only read once per year, as this is called for every day of a run.
+pub fn event_days(year: u32) -> u8 {
+    static EVENT_DAYS: OnceLock<Mutex<HashMap<u32, u8>>> = OnceLock::new();
+    let mut cache = EVENT_DAYS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *cache.entry(year).or_insert_with(|| {
+        let manifest = repository_root().join(year.to_string()).join("Cargo.toml");
+        fs::read_to_string(manifest)
+            .ok()
+            .and_then(|manifest| parse_event_days(&manifest))
+            .unwrap_or_else(|| default_event_days(year))
+    })
+}
+
+/// Number of puzzles of the event of `year` when it is not configured.
+pub fn default_event_days(year: u32) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+fn parse_event_days(manifest: &str) -> Option<u8> {
+    let manifest: DocumentMut = manifest.parse().ok()?;
+    let days = manifest
+        .get("package")?
+        .get("metadata")?
+        .get("aoc")?
+        .get("days")?
+        .as_integer()?;
+    u8::try_from(days)
+        .ok()
+        .filter(|days| (1..=25).contains(days))
+}
+
+/// An iterator that yields every day of the currently set year's event, from the 1st to its last day.
+/// Without a set year, it yields every day from the 1st to the 25th.
 pub fn all_days() -> AllDays {
-    AllDays::new()
+    AllDays::new(get_year().map_or(25, event_days))
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the `last`.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    /// Creates an iterator up to and including `last`, which is capped at the 25th.
+    pub fn new(last: u8) -> Self {
+        Self {
+            current: 1,
+            last: last.min(25),
+        }
     }
 }
 
@@ -121,7 +190,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
@@ -154,11 +223,11 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{default_event_days, parse_event_days, AllDays, Day};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::new(25);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +256,35 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn short_event_iterator() {
+        let days: Vec<Day> = AllDays::new(12).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
+
+    #[test]
+    fn event_days_by_year() {
+        assert_eq!(default_event_days(2015), 25);
+        assert_eq!(default_event_days(2024), 25);
+        assert_eq!(default_event_days(2025), 12);
+    }
+
+    #[test]
+    fn configured_event_days() {
+        let manifest =
+            "[package]\nname = \"advent_of_code_2019\"\n\n[package.metadata.aoc]\ndays = 12\n";
+        assert_eq!(parse_event_days(manifest), Some(12));
+        assert_eq!(
+            parse_event_days("[package]\nname = \"advent_of_code_2019\"\n"),
+            None
+        );
+        assert_eq!(
+            parse_event_days("[package.metadata.aoc]\ndays = 30\n"),
+            None
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,289 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub mod answers;
+pub mod aoc_cli;
+pub mod commands;
+pub mod readme_benchmarks;
+pub mod registry;
+pub mod run_multi;
+pub mod runner;
+pub mod timings;
+
+pub use day::*;
+
+mod day;
+
+pub const ANSI_ITALIC: &str = "\x1b[3m";
+pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Helper function that reads a text file of the year in `year_dir` to a string.
+#[must_use]
+pub fn read_year_file(year_dir: &Path, folder: &str, day: Day) -> String {
+    let filepath = year_dir.join("data").join(folder).join(format!("{day}.txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file of the year in `year_dir` to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_year_file_part(year_dir: &Path, folder: &str, day: Day, part: u8) -> String {
+    let filepath = year_dir
+        .join("data")
+        .join(folder)
+        .join(format!("{day}-{part}.txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Root directory of the repository, which contains the workspace manifest and every year's directory.
+pub fn repository_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Directory of the currently set year, e.g. `<repository>/2024`.
+pub fn year_directory() -> PathBuf {
+    repository_root().join(get_year_exit_on_fail().to_string())
+}
+
+pub fn get_year() -> Option<u32> {
+    std::env::var("AOC_YEAR")
+        .ok()
+        .and_then(|x| x.parse::<u32>().ok())
+}
+
+pub fn get_year_exit_on_fail() -> u32 {
+    let year = get_year();
+    if year.is_none() {
+        eprintln!("Failed to get the currently set AOC year");
+        std::process::exit(1);
+    }
+    year.unwrap()
+}
+
+/// Exits if the event of the currently set year has no puzzle on `day`.
+pub fn check_day_exit_on_fail(day: Day) {
+    let year = get_year_exit_on_fail();
+    if !day.is_in_event(year) {
+        eprintln!(
+            "The {year} event only has {} days, there is no day {day}",
+            event_days(year)
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Years that are members of the workspace, in ascending order.
+pub fn workspace_years() -> Vec<u32> {
+    let manifest = repository_root().join("Cargo.toml");
+    match fs::read_to_string(manifest) {
+        Ok(contents) => parse_workspace_years(&contents),
+        Err(_) => {
+            eprintln!("failed to read Cargo.toml");
+            vec![]
+        }
+    }
+}
+
+fn parse_workspace_years(manifest: &str) -> Vec<u32> {
+    let Ok(manifest) = manifest.parse::<toml_edit::DocumentMut>() else {
+        return vec![];
+    };
+    let members = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(toml_edit::Item::as_array);
+    let mut years: Vec<u32> = members
+        .into_iter()
+        .flatten()
+        .filter_map(|member| member.as_str()?.parse().ok())
+        .collect();
+    years.sort_unstable();
+    years
+}
+
+/// Points every following command of this process, including spawned `cargo` commands, at `year`.
+/// Exits if the year is not a member of the workspace.
+pub fn set_year_override(year: u32) {
+    if !workspace_years().contains(&year) {
+        eprintln!(
+            "{year} is not a member of the workspace. Create it with `cargo new-year {year}`."
+        );
+        std::process::exit(1);
+    }
+    env::set_var("AOC_YEAR", year.to_string());
+}
+
+/// Points the template at the year of the crate named `package`, e.g. `advent_of_code_2024`.
+/// Year crates call this on startup, so their commands ignore the year configured for the repository.
+pub fn set_year_from_package(package: &str) {
+    let year = package
+        .strip_prefix("advent_of_code_")
+        .and_then(|year| year.parse::<u32>().ok());
+    if let Some(year) = year {
+        env::set_var("AOC_YEAR", year.to_string());
+    }
+}
+
+/// Sets up the library crate of a year.
+///
+/// Re-exports the shared macros and the template. The template's `read_file` and `read_file_part` read from the year's own `data` directory.
+///
+/// With the `dhat-heap` feature, it also installs DHAT as the global allocator of every binary of the year.
+/// The `solutions` binary compiles all days into one binary, so the days cannot each install their own.
+#[macro_export]
+macro_rules! year_crate {
+    () => {
+        pub use $crate::{day, solution, solution_tests};
+
+        #[cfg(feature = "dhat-heap")]
+        #[global_allocator]
+        static ALLOC: dhat::Alloc = dhat::Alloc;
+
+        pub mod template {
+            pub use $crate::template::*;
+
+            /// Helper function that reads a text file to a string.
+            #[must_use]
+            pub fn read_file(folder: &str, day: Day) -> String {
+                read_year_file(
+                    ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+                    folder,
+                    day,
+                )
+            }
+
+            /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+            #[must_use]
+            pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+                read_year_file_part(
+                    ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+                    folder,
+                    day,
+                    part,
+                )
+            }
+        }
+    };
+}
+
+/// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// It also creates the constant `SOLUTION`, which the year's `solutions` binary collects into its registry.
+///
+/// With `parse: <function>`, e.g. `solution!(8, parse: parse_input)`, the input is parsed once and both parts
+/// take a reference to the parsed input instead of `&str`. Parsing is timed separately from the parts.
+/// The parsed input must not borrow from the input.
+///
+/// A last block declares named parameters that differ between the examples and the real input,
+/// e.g. `solution!(15, { ROW: example 10, real 2_000_000 })`. They are collected in the constant `PARAMS`.
+/// Parts that take a second argument `&Context` can read them with `context.param("ROW")`.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, 1 $(, $($options:tt)*)?) => {
+        $crate::solution!(@options $day, [[part_one, 1]]; $($($options)*)?);
+    };
+    ($day:expr, 2 $(, $($options:tt)*)?) => {
+        $crate::solution!(@options $day, [[part_two, 2]]; $($($options)*)?);
+    };
+    ($day:expr $(, $($options:tt)*)?) => {
+        $crate::solution!(@options $day, [[part_one, 1] [part_two, 2]]; $($($options)*)?);
+    };
+
+    (@options $day:expr, $parts:tt; parse: $parse:path $(, { $($params:tt)* })?) => {
+        $crate::solution!(@impl $day, $parts, [$parse]; $($($params)*)?);
+    };
+    (@options $day:expr, $parts:tt; $({ $($params:tt)* })?) => {
+        $crate::solution!(@impl $day, $parts, []; $($($params)*)?);
+    };
+
+    (@parse_fn []) => { None };
+    (@parse_fn [$parse:path]) => {
+        Some(|input| -> Box<dyn ::std::any::Any> { Box::new($parse(input)) })
+    };
+    (@part_input [], $input:ident) => { $input.raw() };
+    (@part_input [$parse:path], $input:ident) => { $input.parsed(&$parse) };
+    (@main_input [], $input:ident) => { $input.as_str() };
+    (@main_input [$parse:path], $input:ident) => {
+        &$crate::template::runner::run_parse($parse, &$input, DAY)
+    };
+
+    (@impl $day:expr, [$( [$func:expr, $part:expr] )*], $parse:tt; $( $name:ident : example $example:expr, real $real:expr );* $(;)?) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
+
+        /// Named parameters of this day, see [`Context::param`]($crate::template::runner::Context::param).
+        #[allow(dead_code)]
+        const PARAMS: &[$crate::template::runner::Param] = &[$(
+            $crate::template::runner::Param { name: stringify!($name), example: $example, real: $real },
+        )*];
+
+        /// Registry entry for this day, used to run every solution of a year in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            day: DAY,
+            parse: $crate::solution!(@parse_fn $parse),
+            params: PARAMS,
+            parts: &[$( ($part, |input, context| {
+                let input = $crate::solution!(@part_input $parse, input);
+                $crate::template::runner::call_part(&$func, input, context).map(|result| result.to_string())
+            }), )*],
+        };
+
+        fn main() {
+            use $crate::template::runner::*;
+            $crate::template::set_year_from_package(env!("CARGO_PKG_NAME"));
+            let input = $crate::template::read_year_file(
+                ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+                "inputs",
+                DAY,
+            );
+            let input = $crate::solution!(@main_input $parse, input);
+            let context = Context::real(PARAMS);
+            $( run_part($func, input, &context, DAY, $part); )*
+        }
+    };
+}
+
+/// Creates a test for every example listed in the day's `data/examples/DD.toml`.
+///
+/// Every `[[example]]` of the manifest names an example `file`, the `part` it tests and the expected `answer`,
+/// and can set the `name` of its test. The tests are generated by the year's build script
+/// and run every part through `SOLUTION`, with the example values of the day's parameters.
+#[macro_export]
+macro_rules! solution_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::template::runner::Context;
+
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/example_tests/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_workspace_years;
+
+    #[test]
+    fn parses_workspace_years() {
+        let manifest = "[workspace]\nmembers = [\"year_template\", \"2024\", \"2022\"]\n\n[lib]\n";
+        assert_eq!(parse_workspace_years(manifest), vec![2022, 2024]);
+        assert_eq!(parse_workspace_years("[package]\n"), Vec::<u32>::new());
+    }
+}
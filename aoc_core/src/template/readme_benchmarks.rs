@@ -0,0 +1,299 @@
+/// Module that updates the readme me with timing information.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{event_days, repository_root, Day};
+
+static MARKER: &str = "<!--- benchmarking table --->";
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+    /// The timings of a year could not be read.
+    Timings(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+pub struct TablePosition {
+    pos_start: usize,
+    pos_end: usize,
+}
+
+/// Path of a solution relative to the repository root.
+#[must_use]
+pub fn get_path_for_bin(year: u32, day: Day) -> String {
+    format!("{year}/src/bin/{day}.rs")
+}
+
+fn locate_table(readme: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(
+            "{}: too many occurences of marker in README.".into(),
+        ));
+    }
+
+    let pos_start = matches
+        .first()
+        .map(|m| m.0)
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Build a summary of all years, with how many of the event's days are fully benched,
+/// followed by a collapsible per-day table for every year with stored timings.
+fn construct_table(prefix: &str, years: &[(u32, Timings)]) -> String {
+    let header = format!("{prefix} Benchmarks");
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Year | Days | Total | Slowest day |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for (year, timings) in years {
+        let complete = timings
+            .data
+            .iter()
+            .filter(|t| timings.is_day_complete(t.day, *year))
+            .count();
+        let days = format!("{complete}/{}", event_days(*year));
+        let slowest = timings
+            .data
+            .iter()
+            .max_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos));
+        lines.push(match slowest {
+            Some(slowest) => format!(
+                "| {year} | {days} | {:.2}ms | [Day {}]({}) (`{}`) |",
+                timings.total_millis(),
+                slowest.day.into_inner(),
+                get_path_for_bin(*year, slowest.day),
+                format_nanos(Some(slowest.total_nanos))
+            ),
+            None => format!("| {year} | {days} | - | - |"),
+        });
+    }
+
+    for (year, timings) in years.iter().filter(|(_, t)| !t.data.is_empty()) {
+        lines.extend([
+            String::new(),
+            "<details>".into(),
+            format!("<summary>{year}</summary>"),
+            String::new(),
+        ]);
+        lines.extend(construct_year_table(*year, timings));
+        lines.push("</details>".into());
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn construct_year_table(year: u32, timings: &Timings) -> Vec<String> {
+    let mut lines: Vec<String> = vec![
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---:  |".into(),
+    ];
+
+    for Timing {
+        day,
+        part_1_nanos,
+        part_2_nanos,
+        ..
+    } in &timings.data
+    {
+        lines.push(format!(
+            "| [Day {}]({}) | `{}` | `{}` |",
+            day.into_inner(),
+            get_path_for_bin(year, *day),
+            format_nanos(*part_1_nanos),
+            format_nanos(*part_2_nanos)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {:.2}ms**", timings.total_millis()));
+    lines.push(String::new());
+    lines
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    nanos.map_or_else(
+        || "-".into(),
+        |nanos| format!("{:.1?}", Duration::from_nanos(nanos.round() as u64)),
+    )
+}
+
+fn update_content(s: &mut String, years: &[(u32, Timings)]) -> Result<(), Error> {
+    let positions = locate_table(s)?;
+    let table = construct_table("##", years);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Year directories of the repository, e.g. `2024/`, in ascending order.
+fn year_directories(root: &Path) -> Result<Vec<u32>, Error> {
+    let mut years: Vec<u32> = fs::read_dir(root)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().join("data").is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .filter(|year| (1000..10000).contains(year))
+        .collect();
+    years.sort_unstable();
+    Ok(years)
+}
+
+/// Rebuild the benchmark tables in the repository's README from every year's `data/timings.json`.
+pub fn update() -> Result<(), Error> {
+    let root = repository_root();
+    // a year whose timings cannot be read fails the update instead of losing its table.
+    let years: Vec<(u32, Timings)> = year_directories(&root)?
+        .into_iter()
+        .map(|year| {
+            let path = root
+                .join(year.to_string())
+                .join("data")
+                .join("timings.json");
+            Timings::read_from_path(&path)
+                .map(|timings| (year, timings))
+                .map_err(Error::Timings)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let path = root.join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, &years)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::{day, template::timings::Timing, template::timings::Timings};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1_nanos: Some(10_000_000_f64),
+                    part_2_nanos: Some(20_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 3e+7,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1_nanos: Some(30_000_000_f64),
+                    part_2_nanos: Some(40_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 7e+7,
+                },
+                Timing {
+                    day: day!(4),
+                    part_1_nanos: Some(40_000_000_f64),
+                    part_2_nanos: Some(50_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 9e+7,
+                },
+            ],
+            history: vec![],
+        }
+    }
+
+    fn get_mock_years() -> Vec<(u32, Timings)> {
+        vec![(2022, Timings::default()), (2024, get_mock_timings())]
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &get_mock_years()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_too_many_markers_present() {
+        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
+        update_content(&mut s, &get_mock_years()).unwrap();
+    }
+
+    #[test]
+    fn updates_empty_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_years()).unwrap();
+        assert_eq!(s.contains("## Benchmarks"), true);
+    }
+
+    #[test]
+    fn updates_existing_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_years()).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn format_benchmarks() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_years()).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Year | Days | Total | Slowest day |",
+            "| :---: | :---: | :---: | :---: |",
+            "| 2022 | 0/25 | - | - |",
+            "| 2024 | 3/25 | 190.00ms | [Day 4](2024/src/bin/04.rs) (`90.0ms`) |",
+            "",
+            "<details>",
+            "<summary>2024</summary>",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](2024/src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](2024/src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](2024/src/bin/04.rs) | `40.0ms` | `50.0ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "",
+            "</details>",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -0,0 +1,211 @@
+//! Code generation for the build script of every year.
+//!
+//! Generates the registry of solutions that the `solutions` binary runs in-process.
+//! Every `src/bin/DD.rs` is compiled as a module of that binary and its `SOLUTION` is collected.
+//! Days listed in `AOC_UNCOMPILED_DAYS` do not compile and are left out, so they don't break the other days.
+//!
+//! Also generates the tests that `solution_tests!()` includes from every day's `data/examples/DD.toml`.
+use std::{
+    collections::HashSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{DocumentMut, Item, Value};
+
+use crate::template::run_multi::{uncompiled_days, UNCOMPILED_DAYS_ENV_VAR};
+
+/// Generate the registry and the example tests of the year whose build script calls this.
+pub fn generate() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let bin_dir = manifest_dir.join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+    println!("cargo:rerun-if-env-changed={UNCOMPILED_DAYS_ENV_VAR}");
+    let uncompiled: Vec<u8> = uncompiled_days()
+        .into_iter()
+        .map(|day| day.into_inner())
+        .collect();
+
+    let mut days: Vec<(u8, PathBuf)> = fs::read_dir(&bin_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| {
+            let day = path.file_stem()?.to_str()?.parse::<u8>().ok()?;
+            Some((day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut registry = String::new();
+    for (day, path) in days.iter().filter(|(day, _)| !uncompiled.contains(day)) {
+        // the days' own tests already run as part of their binaries.
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod day_{day:02};\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    let entries: Vec<String> = days
+        .iter()
+        .filter(|(day, _)| !uncompiled.contains(day))
+        .map(|(day, _)| format!("day_{day:02}::SOLUTION"))
+        .collect();
+    registry.push_str(&format!(
+        "#[cfg(not(test))]\nconst SOLUTIONS: &[Solution] = &[{}];\n\n",
+        entries.join(", ")
+    ));
+    registry.push_str("#[cfg(test)]\nconst SOLUTIONS: &[Solution] = &[];\n");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("registry.rs"), registry).unwrap();
+
+    let examples_dir = manifest_dir.join("data").join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+    let tests_dir = out_dir.join("example_tests");
+    fs::create_dir_all(&tests_dir).unwrap();
+    for (day, _) in &days {
+        let manifest = examples_dir.join(format!("{day:02}.toml"));
+        let tests = match fs::read_to_string(&manifest) {
+            Ok(contents) => example_tests(&manifest, &contents, &examples_dir),
+            Err(_) => String::new(),
+        };
+        fs::write(tests_dir.join(format!("{day:02}.rs")), tests).unwrap();
+    }
+}
+
+/// Keywords of the 2021 edition, which cannot name a test function.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn fail(manifest: &Path, message: &str) -> ! {
+    panic!("{}: {message}", manifest.display())
+}
+
+/// A `#[test]` for every `[[example]]` of a day's example manifest.
+fn example_tests(manifest: &Path, contents: &str, examples_dir: &Path) -> String {
+    let document: DocumentMut = contents
+        .parse()
+        .unwrap_or_else(|e| fail(manifest, &format!("invalid TOML: {e}")));
+    let Some(examples) = document.get("example") else {
+        return String::new();
+    };
+    let examples = examples
+        .as_array_of_tables()
+        .unwrap_or_else(|| fail(manifest, "expected `[[example]]` tables"));
+
+    let mut tests = String::new();
+    let mut names: HashSet<String> = HashSet::new();
+    for example in examples {
+        let file = example
+            .get("file")
+            .and_then(Item::as_str)
+            .unwrap_or_else(|| fail(manifest, "every example needs a `file`"));
+        let (part, function) = match example.get("part").and_then(Item::as_integer) {
+            Some(1) => (1, "part_one"),
+            Some(2) => (2, "part_two"),
+            _ => fail(manifest, &format!("`part` of {file} must be 1 or 2")),
+        };
+        // registered parts return their answer as a string.
+        let expected = match example.get("answer").and_then(Item::as_value) {
+            Some(Value::Integer(answer)) => format!("Some(String::from(\"{}\"))", answer.value()),
+            Some(Value::String(answer)) => format!("Some(String::from({:?}))", answer.value()),
+            _ => fail(
+                manifest,
+                &format!("`answer` of {file} must be an integer or a string"),
+            ),
+        };
+        let name = match example.get("name").and_then(Item::as_str) {
+            Some(name) => name.to_string(),
+            None => format!("{function}_{}", file.trim_end_matches(".txt")),
+        };
+        let name: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            || name == "_"
+            || KEYWORDS.contains(&name.as_str())
+        {
+            fail(
+                manifest,
+                &format!(
+                    "the example of part {part} with file {file} is named `{name}`, which is not a valid function name, give it a `name` that starts with a letter and is not a keyword"
+                ),
+            );
+        }
+        if !names.insert(name.clone()) {
+            fail(
+                manifest,
+                &format!(
+                    "the example of part {part} with file {file} is named `{name}` like an earlier example, give it a unique `name`"
+                ),
+            );
+        }
+
+        tests.push_str(&format!(
+            "#[test]\nfn {name}() {{\n    let input = ::std::fs::read_to_string({:?}).expect(\"could not open example file\");\n    assert_eq!(SOLUTION.solve({part}, &input, &Context::example(PARAMS)), {expected});\n}}\n\n",
+            examples_dir.join(file).display().to_string()
+        ));
+    }
+    tests
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::example_tests;
+    use std::path::Path;
+
+    const MANIFEST: &str = "[[example]]\nfile = \"01.txt\"\npart = 1\nanswer = 11\n\n[[example]]\nfile = \"01.txt\"\npart = 2\nanswer = \"abc\"\n";
+
+    #[test]
+    fn generates_example_tests() {
+        let tests = example_tests(Path::new("01.toml"), MANIFEST, Path::new("examples"));
+        assert!(tests.contains("fn part_one_01()"));
+        assert!(tests.contains(
+            "SOLUTION.solve(1, &input, &Context::example(PARAMS)), Some(String::from(\"11\"))"
+        ));
+        assert!(tests.contains("fn part_two_01()"));
+        assert!(tests.contains("Some(String::from(\"abc\"))"));
+    }
+
+    #[test]
+    fn accepts_named_duplicates() {
+        let manifest = format!(
+            "{MANIFEST}\n[[example]]\nfile = \"01.txt\"\npart = 1\nanswer = 12\nname = \"other\"\n"
+        );
+        let tests = example_tests(Path::new("01.toml"), &manifest, Path::new("examples"));
+        assert!(tests.contains("fn other()"));
+    }
+
+    #[test]
+    #[should_panic(expected = "is named `1st`, which is not a valid function name")]
+    fn fails_for_names_starting_with_a_digit() {
+        let manifest = "[[example]]\nfile = \"01.txt\"\npart = 1\nanswer = 11\nname = \"1st\"\n";
+        example_tests(Path::new("01.toml"), manifest, Path::new("examples"));
+    }
+
+    #[test]
+    #[should_panic(expected = "is named `fn`, which is not a valid function name")]
+    fn fails_for_keyword_names() {
+        let manifest = "[[example]]\nfile = \"01.txt\"\npart = 1\nanswer = 11\nname = \"fn\"\n";
+        example_tests(Path::new("01.toml"), manifest, Path::new("examples"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the example of part 1 with file 01.txt is named `part_one_01` like an earlier example"
+    )]
+    fn fails_for_duplicate_test_names() {
+        let manifest =
+            format!("{MANIFEST}\n[[example]]\nfile = \"01.txt\"\npart = 1\nanswer = 12\n");
+        example_tests(Path::new("01.toml"), &manifest, Path::new("examples"));
+    }
+}
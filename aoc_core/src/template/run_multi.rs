@@ -0,0 +1,850 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{collections::HashSet, env, fs, io, path::PathBuf};
+
+use crate::template::runner::{
+    parse_and_print, parse_silently, print_parse_result, print_part_json, print_part_result,
+    run_and_print, run_silently, Context, OutputFormat, ParseResult, PartInput, PartResult,
+    PartStatus, Solution,
+};
+use crate::template::{get_year, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+use super::{
+    all_days,
+    timings::{Timing, TimingRecord, Timings},
+};
+
+/// Structured results of running a single day.
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub day: Day,
+    pub status: DayStatus,
+    /// Only present if the day has a `parse` function and ran to completion.
+    pub parse: Option<ParseResult>,
+    /// Empty unless the day ran to completion.
+    pub parts: Vec<PartResult>,
+}
+
+/// How running a day ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Every part returned, with or without an answer.
+    Completed,
+    /// A part panicked with the contained message.
+    Failed(String),
+    TimedOut(Duration),
+    /// The day does not compile and was left out of the `solutions` binary.
+    NotCompiled,
+    NotScaffolded,
+    MissingInput,
+}
+
+/// Environment variable listing the days that were left out of a year's `solutions` binary because they do not compile.
+/// The year's build script leaves them out of the registry, and the binary reports them.
+pub const UNCOMPILED_DAYS_ENV_VAR: &str = "AOC_UNCOMPILED_DAYS";
+
+/// Days that were left out of the `solutions` binary because they do not compile.
+#[must_use]
+pub fn uncompiled_days() -> Vec<Day> {
+    env::var(UNCOMPILED_DAYS_ENV_VAR)
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|day| day.parse().ok())
+        .collect()
+}
+
+/// Run the registered solutions for a set of days in-process, printing their output and a summary.
+///
+/// Every day runs on its own thread, so a panicking day is reported as failed instead of ending the run.
+/// A day that exceeds `timeout` is reported as timed out and abandoned; its thread cannot be stopped
+/// and keeps running in the background until the process exits. With a timeout, a day's output is only
+/// printed once it finished, so an abandoned day never prints into the output of later days.
+/// With more than one job, days run in parallel unless they are timed.
+pub fn run_multi(
+    solutions: &'static [Solution],
+    days_to_run: &HashSet<Day>,
+    is_timed: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    format: OutputFormat,
+) -> Vec<DayResult> {
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    // benchmarks would disturb each other, so they always run one at a time.
+    let jobs = if is_timed { 1 } else { jobs.max(1) };
+    // a single job prints parts as they finish, multiple jobs hold back a day's output until it is its turn.
+    // so does a timeout, as the output of a day that timed out would end up in the output of the next days.
+    let is_live = jobs == 1 && timeout.is_none();
+
+    let mut results: Vec<DayResult> = Vec::with_capacity(days.len());
+    let mut finished: HashMap<usize, DayResult> = HashMap::new();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut next_start = 0;
+    let (sender, receiver) = mpsc::channel();
+
+    while results.len() < days.len() {
+        // live output has to print a skipped day's status before the next day's header.
+        while running.len() < jobs && next_start < days.len() && (!is_live || finished.is_empty()) {
+            let (index, day) = (next_start, days[next_start]);
+            next_start += 1;
+
+            if is_live {
+                print_day_header(day, index == 0, format);
+            }
+
+            match load_day(solutions, day) {
+                Ok((solution, input)) => {
+                    let sender = sender.clone();
+                    thread::spawn(move || {
+                        let outcome = run_parts(solution, &input, is_timed, is_live, format);
+                        // the receiver is gone if the day timed out.
+                        let _ = sender.send((index, outcome));
+                    });
+                    running.insert(index, Instant::now());
+                }
+                Err(status) => {
+                    finished.insert(index, skipped(day, status));
+                }
+            }
+        }
+
+        let deadline = timeout.and_then(|timeout| running.values().min().map(|s| *s + timeout));
+        let received = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None if !running.is_empty() => {
+                receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            }
+            None => Err(RecvTimeoutError::Timeout),
+        };
+
+        match received {
+            Ok((index, outcome)) => {
+                running.remove(&index);
+                let day = days[index];
+                finished.insert(
+                    index,
+                    match outcome {
+                        Ok((parse, parts)) => DayResult {
+                            day,
+                            status: DayStatus::Completed,
+                            parse,
+                            parts,
+                        },
+                        Err(message) => skipped(day, DayStatus::Failed(message)),
+                    },
+                );
+            }
+            Err(_) => {
+                if let Some(timeout) = timeout {
+                    let expired: Vec<usize> = running
+                        .iter()
+                        .filter(|(_, started)| started.elapsed() >= timeout)
+                        .map(|(index, _)| *index)
+                        .collect();
+                    for index in expired {
+                        running.remove(&index);
+                        finished.insert(index, skipped(days[index], DayStatus::TimedOut(timeout)));
+                    }
+                }
+            }
+        }
+
+        while let Some(result) = finished.remove(&results.len()) {
+            print_day_result(&result, results.is_empty(), is_live, format);
+            results.push(result);
+        }
+    }
+
+    if is_timed && format == OutputFormat::Text {
+        let total_millis = to_timings(&results).total_millis();
+        println!(
+            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        );
+    }
+
+    if is_timed {
+        print_timeout_note(&results, format);
+    }
+
+    print_summary(&results, format);
+
+    results
+}
+
+/// Parse the input of a solution if it has a `parse` function and run all of its parts, catching panics.
+fn run_parts(
+    solution: &Solution,
+    input: &str,
+    is_timed: bool,
+    is_live: bool,
+    format: OutputFormat,
+) -> Result<(Option<ParseResult>, Vec<PartResult>), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = solution.parse.map(|parse| {
+            if is_live {
+                parse_and_print(parse, input, solution.day, is_timed, format)
+            } else {
+                parse_silently(parse, input)
+            }
+        });
+        let part_input = match &parsed {
+            Some((parsed, _)) => PartInput::Parsed(parsed.as_ref()),
+            None => PartInput::Raw(input),
+        };
+        let context = Context::real(solution.params);
+
+        let parts = solution
+            .parts
+            .iter()
+            .map(|(part, func)| {
+                let func = |input| func(input, &context);
+                if is_live {
+                    run_and_print(func, part_input, solution.day, *part, is_timed, format)
+                } else {
+                    run_silently(func, part_input, *part)
+                }
+            })
+            .collect();
+        (parsed.map(|(_, result)| result), parts)
+    }))
+    .map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".into())
+}
+
+fn skipped(day: Day, status: DayStatus) -> DayResult {
+    DayResult {
+        day,
+        status,
+        parse: None,
+        parts: vec![],
+    }
+}
+
+/// Find the registered solution of a day and read its input.
+fn load_day(
+    solutions: &'static [Solution],
+    day: Day,
+) -> Result<(&'static Solution, String), DayStatus> {
+    if uncompiled_days().contains(&day) {
+        return Err(DayStatus::NotCompiled);
+    }
+    let solution = solutions
+        .iter()
+        .find(|s| s.day == day)
+        .ok_or(DayStatus::NotScaffolded)?;
+    let input = fs::read_to_string(get_input_path(day)).map_err(|_| DayStatus::MissingInput)?;
+    Ok((solution, input))
+}
+
+fn print_day_header(day: Day, is_first: bool, format: OutputFormat) {
+    if format == OutputFormat::Text {
+        if !is_first {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+}
+
+/// Print the output of a day that was not printed while it ran.
+fn print_day_result(result: &DayResult, is_first: bool, is_live: bool, format: OutputFormat) {
+    if !is_live {
+        print_day_header(result.day, is_first, format);
+        if let Some(parse) = &result.parse {
+            print_parse_result(result.day, parse, format);
+        }
+        for part in &result.parts {
+            print_part_result(result.day, part, format);
+        }
+    }
+
+    let status = match &result.status {
+        DayStatus::Completed => return,
+        DayStatus::Failed(_) => PartStatus::Failed,
+        DayStatus::TimedOut(_) => PartStatus::TimedOut,
+        DayStatus::NotCompiled => PartStatus::NotCompiled,
+        DayStatus::NotScaffolded => PartStatus::NotScaffolded,
+        DayStatus::MissingInput => PartStatus::MissingInput,
+    };
+
+    match format {
+        OutputFormat::Text => match &result.status {
+            DayStatus::Failed(message) => println!("✖ panicked: {message}"),
+            DayStatus::TimedOut(timeout) => println!("✖ timed out after {timeout:.1?}"),
+            DayStatus::NotCompiled => println!("✖ does not compile"),
+            DayStatus::MissingInput => println!("Missing input."),
+            _ => println!("Not solved."),
+        },
+        OutputFormat::Json => {
+            let parts = get_year().map_or(2, |year| result.day.parts(year));
+            for part in 1..=parts {
+                print_part_json(result.day, part, None, status);
+            }
+        }
+    }
+}
+
+/// Whether every part of a day returned an answer.
+fn is_solved(result: &DayResult) -> bool {
+    let parts = get_year().map_or(2, |year| result.day.parts(year));
+    result.status == DayStatus::Completed
+        && (1..=parts).all(|part| {
+            result
+                .parts
+                .iter()
+                .any(|r| r.part == part && r.answer.is_some())
+        })
+}
+
+/// Summarize how many days were solved, failed, timed out or are not scaffolded yet.
+fn summarize(results: &[DayResult]) -> String {
+    let count = |f: fn(&DayStatus) -> bool| results.iter().filter(|r| f(&r.status)).count();
+    let mut summary = format!(
+        "{} solved, {} failed, {} timed out, {} not scaffolded",
+        results.iter().filter(|r| is_solved(r)).count(),
+        count(|s| matches!(s, DayStatus::Failed(_))),
+        count(|s| matches!(s, DayStatus::TimedOut(_))),
+        count(|s| *s == DayStatus::NotScaffolded),
+    );
+
+    let unsolved = results
+        .iter()
+        .filter(|r| r.status == DayStatus::Completed && !is_solved(r))
+        .count();
+    if unsolved > 0 {
+        summary.push_str(&format!(", {unsolved} unsolved"));
+    }
+    let not_compiled = count(|s| *s == DayStatus::NotCompiled);
+    if not_compiled > 0 {
+        summary.push_str(&format!(", {not_compiled} not compiled"));
+    }
+    let missing_input = count(|s| *s == DayStatus::MissingInput);
+    if missing_input > 0 {
+        summary.push_str(&format!(", {missing_input} missing input"));
+    }
+
+    for (label, filter) in [
+        (
+            "Failed",
+            (|s| matches!(s, DayStatus::Failed(_))) as fn(&DayStatus) -> bool,
+        ),
+        ("Timed out", |s| matches!(s, DayStatus::TimedOut(_))),
+        ("Not compiled", |s| *s == DayStatus::NotCompiled),
+    ] {
+        let days: Vec<String> = results
+            .iter()
+            .filter(|r| filter(&r.status))
+            .map(|r| r.day.to_string())
+            .collect();
+        if !days.is_empty() {
+            summary.push_str(&format!("\n{label}: {}", days.join(", ")));
+        }
+    }
+
+    summary
+}
+
+/// Abandoned days keep a CPU core busy, which slows down the benchmarks of the days after them.
+fn print_timeout_note(results: &[DayResult], format: OutputFormat) {
+    let Some(first) = results
+        .iter()
+        .find(|r| matches!(r.status, DayStatus::TimedOut(_)))
+    else {
+        return;
+    };
+    let note = format!(
+        "Note: day {} timed out and kept running in the background, so the timings of later days may be skewed.",
+        first.day
+    );
+    // keep stdout parseable in JSON mode.
+    if format == OutputFormat::Json {
+        eprintln!("{note}");
+    } else {
+        println!("\n{note}");
+    }
+}
+
+fn print_summary(results: &[DayResult], format: OutputFormat) {
+    let summary = summarize(results);
+    // keep stdout parseable in JSON mode.
+    if format == OutputFormat::Json {
+        eprintln!("{summary}");
+    } else {
+        println!("\n{ANSI_BOLD}Summary:{ANSI_RESET} {summary}");
+    }
+}
+
+/// Whether any day panicked, timed out or does not compile.
+#[must_use]
+pub fn has_failures(results: &[DayResult]) -> bool {
+    results.iter().any(|r| {
+        matches!(
+            r.status,
+            DayStatus::Failed(_) | DayStatus::TimedOut(_) | DayStatus::NotCompiled
+        )
+    })
+}
+
+/// Convert benched results to timings. Every solved part is added to the history.
+/// Days that did not run to completion are left out.
+pub fn to_timings(results: &[DayResult]) -> Timings {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let results: Vec<&DayResult> = results
+        .iter()
+        .filter(|r| r.status == DayStatus::Completed)
+        .collect();
+
+    Timings {
+        data: results.iter().copied().map(Timing::from).collect(),
+        history: results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .parts
+                    .iter()
+                    .filter(|r| r.answer.is_some())
+                    .map(move |r| TimingRecord {
+                        day: result.day,
+                        part: r.part,
+                        #[allow(clippy::cast_precision_loss)]
+                        nanos: r.duration.as_nanos() as f64,
+                        timestamp,
+                    })
+            })
+            .collect(),
+    }
+}
+
+impl From<&DayResult> for Timing {
+    fn from(value: &DayResult) -> Self {
+        let mut timing = Timing {
+            day: value.day,
+            part_1_nanos: None,
+            part_2_nanos: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_nanos: None,
+            parse_stats: None,
+            total_nanos: 0_f64,
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let parse_nanos = value.parse.map(|parse| parse.duration.as_nanos() as f64);
+
+        // parts without an answer are treated as unsolved.
+        for result in value.parts.iter().filter(|r| r.answer.is_some()) {
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = result.duration.as_nanos() as f64;
+            match result.part {
+                1 => (timing.part_1_nanos, timing.part_1_stats) = (Some(nanos), result.stats),
+                2 => (timing.part_2_nanos, timing.part_2_stats) = (Some(nanos), result.stats),
+                _ => continue,
+            }
+            timing.total_nanos += nanos;
+        }
+
+        // parsing only counts towards the total of a day with a solved part.
+        if let Some(nanos) = parse_nanos.filter(|_| timing.total_nanos > 0_f64) {
+            timing.parse_nanos = Some(nanos);
+            timing.parse_stats = value.parse.and_then(|parse| parse.stats);
+            timing.total_nanos += nanos;
+        }
+
+        timing
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    /// The `solutions` binary failed to build for a reason other than a day that does not compile.
+    BuildFailed,
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+fn get_input_path(day: Day) -> PathBuf {
+    crate::template::year_directory()
+        .join("data")
+        .join("inputs")
+        .join(format!("{day}.txt"))
+}
+
+/// All solutions of a year are compiled into its `solutions` binary.
+/// This module encapsulates invoking that binary, which runs them in-process.
+pub mod child_commands {
+    use super::{Error, UNCOMPILED_DAYS_ENV_VAR};
+    use crate::template::{get_year_exit_on_fail, Day};
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::process::{self, Command, ExitStatus, Stdio};
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    /// Run the year's `solutions` binary with the given arguments, forwarding its output.
+    /// Days that do not compile are left out of the binary and reported instead of failing the whole run.
+    pub fn run_solutions(args: &[String], is_release: bool) -> Result<ExitStatus, Error> {
+        let year = get_year_exit_on_fail();
+        let year = format!("advent_of_code_{}", year);
+        let uncompiled = build_solutions(&year, is_release)?;
+
+        let mut cmd_args = vec!["run", "-p", &year, "--quiet", "--bin", "solutions"];
+
+        if is_release {
+            cmd_args.push("--release");
+        }
+
+        cmd_args.push("--");
+        cmd_args.extend(args.iter().map(String::as_str));
+
+        let status = Command::new("cargo")
+            .args(&cmd_args)
+            .env(UNCOMPILED_DAYS_ENV_VAR, join_days(&uncompiled))
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()?;
+
+        Ok(status)
+    }
+
+    /// Build the `solutions` binary of `package`, leaving out every day that does not compile.
+    /// Returns the days that were left out.
+    fn build_solutions(package: &str, is_release: bool) -> Result<Vec<Day>, Error> {
+        let mut uncompiled: Vec<Day> = vec![];
+
+        loop {
+            let mut cmd_args = vec![
+                "build",
+                "-p",
+                package,
+                "--quiet",
+                "--bin",
+                "solutions",
+                "--message-format",
+                "json",
+            ];
+            if is_release {
+                cmd_args.push("--release");
+            }
+
+            let output = Command::new("cargo")
+                .args(&cmd_args)
+                .env(UNCOMPILED_DAYS_ENV_VAR, join_days(&uncompiled))
+                .output()?;
+
+            if output.status.success() {
+                for day in &uncompiled {
+                    eprintln!("Day {day} does not compile and is skipped. Run `cargo solve {day}` to see the errors.");
+                }
+                return Ok(uncompiled);
+            }
+
+            let messages = String::from_utf8_lossy(&output.stdout);
+            let failed: Vec<Day> = days_with_errors(&messages)
+                .into_iter()
+                .filter(|day| !uncompiled.contains(day))
+                .collect();
+
+            // the error is not in a day, e.g. in the year's library.
+            if failed.is_empty() {
+                for message in error_messages(&messages) {
+                    eprintln!("{message}");
+                }
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                return Err(Error::BuildFailed);
+            }
+            uncompiled.extend(failed);
+        }
+    }
+
+    fn join_days(days: &[Day]) -> String {
+        days.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Compiler errors in cargo's JSON messages.
+    fn errors(messages: &str) -> impl Iterator<Item = HashMap<String, JsonValue>> + '_ {
+        messages
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .filter_map(|json| {
+                let mut json: HashMap<String, JsonValue> = json.try_into().ok()?;
+                if json.get("reason")?.get::<String>()? != "compiler-message" {
+                    return None;
+                }
+                let message: HashMap<String, JsonValue> =
+                    json.remove("message")?.try_into().ok()?;
+                (message.get("level")?.get::<String>()? == "error").then_some(message)
+            })
+    }
+
+    fn error_messages(messages: &str) -> Vec<String> {
+        errors(messages)
+            .filter_map(|error| error.get("rendered")?.get::<String>().cloned())
+            .collect()
+    }
+
+    /// Days whose `src/bin/DD.rs` has a compiler error, according to cargo's JSON messages.
+    pub(super) fn days_with_errors(messages: &str) -> Vec<Day> {
+        let mut days: Vec<Day> = errors(messages)
+            .flat_map(|error| {
+                let spans = error
+                    .get("spans")
+                    .and_then(|spans| spans.get::<Vec<JsonValue>>())
+                    .cloned()
+                    .unwrap_or_default();
+                spans.into_iter().filter_map(|span| {
+                    let path = span.get::<HashMap<String, JsonValue>>()?.get("file_name")?;
+                    day_of_file(Path::new(path.get::<String>()?))
+                })
+            })
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    fn day_of_file(path: &Path) -> Option<Day> {
+        if path.parent()?.file_name()? != "bin" || path.extension()? != "rs" {
+            return None;
+        }
+        path.file_stem()?.to_str()?.parse().ok()
+    }
+
+    /// Run the year's `solutions` binary and exit with its status code if it fails.
+    pub fn run_solutions_exit_on_fail(args: &[String], is_release: bool) {
+        match run_solutions(args, is_release) {
+            Ok(status) if status.success() => {}
+            Ok(status) => process::exit(status.code().unwrap_or(1)),
+            Err(Error::BuildFailed) => {
+                eprintln!("Failed to build the solutions binary.");
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to run the solutions binary: {e:?}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        child_commands::days_with_errors, run_parts, skipped, summarize, to_timings, DayResult,
+        DayStatus,
+    };
+    use crate::{
+        day,
+        template::{
+            runner::{OutputFormat, ParseResult, PartResult, Solution},
+            timings::Timing,
+        },
+    };
+    use std::time::Duration;
+
+    static PANICS: Solution = Solution {
+        day: day!(16),
+        parse: None,
+        params: &[],
+        parts: &[
+            (1, |input, _| Some(input.raw().len().to_string())),
+            (2, |_, _| todo!("part two")),
+        ],
+    };
+
+    fn parse_words(input: &str) -> Vec<String> {
+        input.split_whitespace().map(str::to_string).collect()
+    }
+
+    static PARSES: Solution = Solution {
+        day: day!(17),
+        parse: Some(|input| Box::new(parse_words(input))),
+        params: &[],
+        parts: &[(1, |input, _| {
+            Some(input.parsed(&parse_words).len().to_string())
+        })],
+    };
+
+    fn part(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(str::to_string),
+            duration: Duration::from_nanos(nanos),
+            samples: 100,
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn converts_results_to_timings() {
+        let result = DayResult {
+            day: day!(1),
+            status: DayStatus::Completed,
+            parse: None,
+            parts: vec![part(1, Some("0"), 74), part(2, Some("10"), 74_130_000)],
+        };
+        let timing = Timing::from(&result);
+        assert_eq!(timing.total_nanos, 74_130_074_f64);
+        assert_eq!(timing.part_1_nanos, Some(74_f64));
+        assert_eq!(timing.part_2_nanos, Some(74_130_000_f64));
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        let result = DayResult {
+            day: day!(1),
+            status: DayStatus::Completed,
+            parse: None,
+            parts: vec![part(1, None, 100), part(2, Some("10"), 2_000)],
+        };
+        let timing = Timing::from(&result);
+        assert_eq!(timing.total_nanos, 2_000_f64);
+        assert_eq!(timing.part_1_nanos.is_none(), true);
+        assert_eq!(timing.part_2_nanos, Some(2_000_f64));
+    }
+
+    #[test]
+    fn records_history_of_solved_parts() {
+        let results = [DayResult {
+            day: day!(1),
+            status: DayStatus::Completed,
+            parse: None,
+            parts: vec![part(1, None, 100), part(2, Some("10"), 2_000)],
+        }];
+        let timings = to_timings(&results);
+        assert_eq!(timings.history.len(), 1);
+        assert_eq!(timings.history[0].part, 2);
+        assert_eq!(timings.history[0].nanos, 2_000_f64);
+    }
+
+    #[test]
+    fn adds_parse_time_to_solved_days() {
+        let mut result = DayResult {
+            day: day!(1),
+            status: DayStatus::Completed,
+            parse: Some(ParseResult {
+                duration: Duration::from_nanos(500),
+                samples: 100,
+                stats: None,
+            }),
+            parts: vec![part(1, Some("0"), 74), part(2, Some("10"), 1_000)],
+        };
+        let timing = Timing::from(&result);
+        assert_eq!(timing.parse_nanos, Some(500_f64));
+        assert_eq!(timing.total_nanos, 1_574_f64);
+
+        result.parts = vec![part(1, None, 74)];
+        let timing = Timing::from(&result);
+        assert_eq!(timing.parse_nanos, None);
+        assert_eq!(timing.total_nanos, 0_f64);
+    }
+
+    #[test]
+    fn runs_parts_on_parsed_input() {
+        let (parse, parts) = run_parts(&PARSES, "a b c", false, false, OutputFormat::Text).unwrap();
+        assert!(parse.is_some());
+        assert_eq!(parts[0].answer.as_deref(), Some("3"));
+        assert_eq!(
+            PARSES.solve(1, "a b", &super::Context::real(&[])),
+            Some("2".into())
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        let outcome = run_parts(&PANICS, "input", false, false, OutputFormat::Text);
+        assert_eq!(outcome.unwrap_err(), "not yet implemented: part two");
+    }
+
+    #[test]
+    fn leaves_incomplete_days_out_of_timings() {
+        let results = [
+            DayResult {
+                day: day!(1),
+                status: DayStatus::Completed,
+                parse: None,
+                parts: vec![part(1, Some("1"), 100)],
+            },
+            skipped(day!(2), DayStatus::TimedOut(Duration::from_secs(1))),
+        ];
+        assert_eq!(to_timings(&results).data.len(), 1);
+    }
+
+    #[test]
+    fn summarizes_results() {
+        let results = [
+            DayResult {
+                day: day!(1),
+                status: DayStatus::Completed,
+                parse: None,
+                parts: vec![part(1, Some("1"), 100), part(2, Some("2"), 100)],
+            },
+            skipped(day!(2), DayStatus::Failed("boom".into())),
+            skipped(day!(3), DayStatus::TimedOut(Duration::from_secs(5))),
+            skipped(day!(4), DayStatus::NotScaffolded),
+            skipped(day!(5), DayStatus::NotScaffolded),
+            skipped(day!(6), DayStatus::NotCompiled),
+        ];
+        assert_eq!(
+            summarize(&results),
+            "1 solved, 1 failed, 1 timed out, 2 not scaffolded, 1 not compiled\nFailed: 02\nTimed out: 03\nNot compiled: 06"
+        );
+    }
+
+    #[test]
+    fn only_counts_days_with_every_answer_as_solved() {
+        let completed = |parts| DayResult {
+            day: day!(1),
+            status: DayStatus::Completed,
+            parse: None,
+            parts,
+        };
+        let results = [
+            completed(vec![part(1, None, 100), part(2, None, 100)]),
+            completed(vec![part(1, Some("1"), 100), part(2, None, 100)]),
+            completed(vec![part(1, Some("1"), 100)]),
+        ];
+        assert_eq!(
+            summarize(&results),
+            "0 solved, 0 failed, 0 timed out, 0 not scaffolded, 3 unsolved"
+        );
+    }
+
+    #[test]
+    fn finds_days_with_compiler_errors() {
+        let messages = [
+            r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code_2025"}}"#,
+            r#"{"reason":"compiler-message","message":{"level":"warning","spans":[{"file_name":"/aoc/2025/src/bin/03.rs"}]}}"#,
+            r#"{"reason":"compiler-message","message":{"level":"error","spans":[{"file_name":"/aoc/2025/src/bin/10.rs"},{"file_name":"/aoc/2025/src/bin/10.rs"}]}}"#,
+            r#"{"reason":"compiler-message","message":{"level":"error","spans":[{"file_name":"src/bin/04.rs"}]}}"#,
+            r#"{"reason":"compiler-message","message":{"level":"error","spans":[{"file_name":"/aoc/2025/src/utils/grid.rs"}]}}"#,
+            r#"{"reason":"build-finished","success":false}"#,
+        ]
+        .join("\n");
+        assert_eq!(days_with_errors(&messages), vec![day!(4), day!(10)]);
+    }
+}
@@ -0,0 +1,885 @@
+/// Encapsulates code that interacts with solution functions.
+use std::any::Any;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io::{stdout, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{cmp, env, process};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::aoc_cli::{self, SubmissionOutcome};
+use crate::template::ANSI_BOLD;
+use crate::template::{Day, ANSI_ITALIC, ANSI_RESET};
+
+/// A solution part with its answer converted to a string.
+pub type SolutionPart = fn(PartInput, &Context) -> Option<String>;
+
+/// A day's `parse` function with its output boxed, so days with different input types can share a registry.
+pub type ParseFn = fn(&str) -> Box<dyn Any>;
+
+/// Entry in a year's solution registry. Created by the `solution!` macro.
+pub struct Solution {
+    pub day: Day,
+    /// Parses the input once for all parts. Without it, the parts take the raw input.
+    pub parse: Option<ParseFn>,
+    pub params: &'static [Param],
+    pub parts: &'static [(u8, SolutionPart)],
+}
+
+impl Solution {
+    /// Run a single part on `input`, parsing it first if the day has a `parse` function.
+    /// Panics if the day has no such part.
+    #[must_use]
+    pub fn solve(&self, part: u8, input: &str, context: &Context) -> Option<String> {
+        let (_, func) = self
+            .parts
+            .iter()
+            .find(|(p, _)| *p == part)
+            .unwrap_or_else(|| panic!("day {} has no part {part}", self.day));
+        match self.parse {
+            Some(parse) => func(PartInput::Parsed(parse(input).as_ref()), context),
+            None => func(PartInput::Raw(input), context),
+        }
+    }
+}
+
+/// Input handed to a registered part: the raw puzzle input, or the output of the day's `parse` function.
+#[derive(Clone, Copy)]
+pub enum PartInput<'a> {
+    Raw(&'a str),
+    Parsed(&'a dyn Any),
+}
+
+impl<'a> PartInput<'a> {
+    /// Panics if the day has a `parse` function.
+    #[must_use]
+    pub fn raw(self) -> &'a str {
+        match self {
+            PartInput::Raw(input) => input,
+            PartInput::Parsed(_) => panic!("expected the raw input, but the day parses its input"),
+        }
+    }
+
+    /// The output of `parse`, which is only passed to infer its type.
+    /// Parts can take it in any borrowed form, e.g. `&[T]` if `parse` returns `Vec<T>`, see [`call_part`].
+    /// Panics if the day takes the raw input.
+    #[must_use]
+    pub fn parsed<P: 'static>(self, _parse: &impl Fn(&str) -> P) -> &'a P {
+        match self {
+            PartInput::Parsed(parsed) => parsed
+                .downcast_ref()
+                .expect("parsed input has an unexpected type"),
+            PartInput::Raw(_) => panic!("expected parsed input, but the day takes the raw input"),
+        }
+    }
+}
+
+/// Whether a solution part runs on an example or on the real puzzle input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+/// A named parameter of a day that differs between the examples and the real input,
+/// e.g. the row to scan or the number of iterations. Declared with the `solution!` macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub example: i64,
+    pub real: i64,
+}
+
+/// Passed to solution parts that take a second argument, to tell them what input they run on.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub input: InputKind,
+    params: &'static [Param],
+    overrides: Vec<(&'static str, i64)>,
+}
+
+impl Context {
+    /// Context for running on the real puzzle input, with the real value of every parameter.
+    #[must_use]
+    pub fn real(params: &'static [Param]) -> Self {
+        Context {
+            input: InputKind::Real,
+            params,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Context for running on an example, with the example value of every parameter.
+    #[must_use]
+    pub fn example(params: &'static [Param]) -> Self {
+        Context {
+            input: InputKind::Example,
+            params,
+            overrides: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_example(&self) -> bool {
+        self.input == InputKind::Example
+    }
+
+    /// Override the value of a declared parameter, e.g. for a test on a custom example.
+    /// Panics if the day does not declare the parameter.
+    #[must_use]
+    pub fn with(mut self, name: &'static str, value: i64) -> Self {
+        self.declared(name);
+        self.overrides.retain(|(n, _)| *n != name);
+        self.overrides.push((name, value));
+        self
+    }
+
+    /// Value of a named parameter for the input this part runs on.
+    /// Panics if the day does not declare the parameter, or if its value does not fit into `T`.
+    #[must_use]
+    pub fn param<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let param = self.declared(name);
+        let value = match self.overrides.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => *value,
+            None if self.is_example() => param.example,
+            None => param.real,
+        };
+        T::try_from(value).unwrap_or_else(|_| {
+            panic!("parameter {name} = {value} does not fit the requested type")
+        })
+    }
+
+    fn declared(&self, name: &str) -> &Param {
+        self.params
+            .iter()
+            .find(|param| param.name == name)
+            .unwrap_or_else(|| panic!("parameter {name} is not declared in solution!"))
+    }
+}
+
+/// Marker for solution parts that only take the input.
+pub struct InputOnly;
+
+/// Marker for solution parts that take the input and a [`Context`].
+pub struct WithContext;
+
+/// A solution part, taking either `&I` or `(&I, &Context)`, where `I` is `str` or the output of `parse`.
+/// The marker `M` lets both kinds of functions implement this trait.
+pub trait Part<I: ?Sized, M, T> {
+    fn call(&self, input: &I, context: &Context) -> Option<T>;
+}
+
+impl<I: ?Sized, F: Fn(&I) -> Option<T>, T> Part<I, InputOnly, T> for F {
+    fn call(&self, input: &I, _: &Context) -> Option<T> {
+        self(input)
+    }
+}
+
+impl<I: ?Sized, F: Fn(&I, &Context) -> Option<T>, T> Part<I, WithContext, T> for F {
+    fn call(&self, input: &I, context: &Context) -> Option<T> {
+        self(input, context)
+    }
+}
+
+/// Call a solution part, passing `context` if it takes one.
+/// `input` is borrowed as the type the part takes, e.g. `&[T]` for a parsed `Vec<T>`.
+pub fn call_part<I: ?Sized, M, T>(
+    func: &impl Part<I, M, T>,
+    input: &(impl Borrow<I> + ?Sized),
+    context: &Context,
+) -> Option<T> {
+    func.call(input.borrow(), context)
+}
+
+/// Structured result of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if the part was benched.
+    pub stats: Option<BenchStats>,
+}
+
+/// Structured result of running a day's `parse` function.
+#[derive(Clone, Copy, Debug)]
+pub struct ParseResult {
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if parsing was benched.
+    pub stats: Option<BenchStats>,
+}
+
+/// Summary of a benchmark. Computed after discarding warm-up iterations and outliers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR beyond the quartiles) that were rejected.
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Compute statistics from the measured samples. Panics if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = (q3 - q1) * 3 / 2;
+        let kept: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1.saturating_sub(fence) && *x <= q3 + fence)
+            .collect();
+
+        let mean = kept.iter().sum::<u128>() / kept.len() as u128;
+        #[allow(clippy::cast_precision_loss)]
+        let variance = kept
+            .iter()
+            .map(|x| (*x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / kept.len() as f64;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = |n: u128| Duration::from_nanos(n as u64);
+        BenchStats {
+            mean: nanos(mean),
+            min: nanos(kept[0]),
+            median: nanos(percentile(&kept, 0.5)),
+            p95: nanos(percentile(&kept, 0.95)),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers: sorted.len() - kept.len(),
+        }
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable, ANSI-decorated text.
+    #[default]
+    Text,
+    /// One JSON object per line and part.
+    Json,
+}
+
+impl OutputFormat {
+    /// Read the `--format` argument of the current process.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|format| format.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Arguments that pass this format on to a child process.
+    #[must_use]
+    pub fn to_args(self) -> Vec<String> {
+        match self {
+            OutputFormat::Text => vec![],
+            OutputFormat::Json => vec!["--format".into(), "json".into()],
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "unknown output format \"{s}\", expected \"text\" or \"json\""
+            )),
+        }
+    }
+}
+
+/// State of a part in machine-readable output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// The solution returned `None`.
+    Unsolved,
+    /// The day has no solution file.
+    NotScaffolded,
+    /// The day has no input file.
+    MissingInput,
+    /// A part of the day panicked.
+    Failed,
+    /// The day exceeded its time limit.
+    TimedOut,
+    /// The day does not compile.
+    NotCompiled,
+}
+
+impl PartStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::NotScaffolded => "not_scaffolded",
+            PartStatus::MissingInput => "missing_input",
+            PartStatus::Failed => "failed",
+            PartStatus::TimedOut => "timed_out",
+            PartStatus::NotCompiled => "not_compiled",
+        }
+    }
+}
+
+/// Build the JSON object describing one part. `result` is `None` if the part was not run.
+#[must_use]
+pub fn part_json(day: Day, part: u8, result: Option<&PartResult>, status: PartStatus) -> JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    let number = |n: u128| JsonValue::Number(n as f64);
+    let year = crate::template::get_year();
+
+    JsonValue::Object(HashMap::from([
+        (
+            "year".into(),
+            year.map_or(JsonValue::Null, |year| number(year.into())),
+        ),
+        ("day".into(), number(day.into_inner().into())),
+        ("part".into(), number(part.into())),
+        (
+            "answer".into(),
+            result
+                .and_then(|r| r.answer.clone())
+                .map_or(JsonValue::Null, JsonValue::String),
+        ),
+        (
+            "duration_ns".into(),
+            result.map_or(JsonValue::Null, |r| number(r.duration.as_nanos())),
+        ),
+        (
+            "samples".into(),
+            result.map_or(JsonValue::Null, |r| number(r.samples)),
+        ),
+        ("status".into(), JsonValue::String(status.as_str().into())),
+    ]))
+}
+
+/// Build the JSON object describing how long parsing the input of a day took.
+#[must_use]
+pub fn parse_json(day: Day, result: &ParseResult) -> JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    let number = |n: u128| JsonValue::Number(n as f64);
+    let year = crate::template::get_year();
+
+    JsonValue::Object(HashMap::from([
+        (
+            "year".into(),
+            year.map_or(JsonValue::Null, |year| number(year.into())),
+        ),
+        ("day".into(), number(day.into_inner().into())),
+        (
+            "parse_duration_ns".into(),
+            number(result.duration.as_nanos()),
+        ),
+        ("samples".into(), number(result.samples)),
+    ]))
+}
+
+/// Print one JSON object on its own line.
+pub fn print_part_json(day: Day, part: u8, result: Option<&PartResult>, status: PartStatus) {
+    println!(
+        "{}",
+        part_json(day, part, result, status).stringify().unwrap()
+    );
+}
+
+/// Parse the input of a day once for all of its parts and print how long that took.
+pub fn run_parse<P>(parse: impl Fn(&str) -> P, input: &str, day: Day) -> P {
+    let is_timed = env::args().any(|x| x == "--time");
+    parse_and_print(parse, input, day, is_timed, OutputFormat::from_args()).0
+}
+
+/// Run a solution part on the real input and submit its answer if requested.
+/// `input` is borrowed as the type the part takes, e.g. `&[T]` for a parsed `Vec<T>`.
+pub fn run_part<I: ?Sized, M, T: Display>(
+    func: impl Part<I, M, T>,
+    input: &(impl Borrow<I> + ?Sized),
+    context: &Context,
+    day: Day,
+    part: u8,
+) {
+    let input = input.borrow();
+    let is_timed = env::args().any(|x| x == "--time");
+    let func = |input: &I| func.call(input, context);
+    let format = OutputFormat::from_args();
+    let result = run_and_print(func, input, day, part, is_timed, format);
+
+    if let Some(answer) = result.answer {
+        submit_result(answer, day, part, format);
+    }
+}
+
+/// Run a day's `parse` function, print how long it took and return the parsed input with its timing.
+pub fn parse_and_print<P>(
+    parse: impl Fn(&str) -> P,
+    input: &str,
+    day: Day,
+    is_timed: bool,
+    format: OutputFormat,
+) -> (P, ParseResult) {
+    let (parsed, duration, samples, stats) = run_timed(parse, input, is_timed, |_| {
+        if format == OutputFormat::Text && is_timed {
+            print!("Parse > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    });
+
+    let parse_result = ParseResult {
+        duration,
+        samples,
+        stats,
+    };
+    print_parse_result(day, &parse_result, format);
+    (parsed, parse_result)
+}
+
+/// Run a day's `parse` function once without printing anything, e.g. on a worker thread.
+pub fn parse_silently<P>(parse: impl Fn(&str) -> P, input: &str) -> (P, ParseResult) {
+    let (parsed, duration, samples, stats) = run_timed(parse, input, false, |_| {});
+    (
+        parsed,
+        ParseResult {
+            duration,
+            samples,
+            stats,
+        },
+    )
+}
+
+/// Print how long parsing the input of a day took.
+pub fn print_parse_result(day: Day, result: &ParseResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            print!("\r");
+            println!("Parse{}", format_duration(&result.duration, result.samples));
+            if let Some(stats) = result.stats.filter(|_| env::args().any(|x| x == "--stats")) {
+                print_stats(&stats);
+            }
+        }
+        OutputFormat::Json => println!("{}", parse_json(day, result).stringify().unwrap()),
+    }
+}
+
+/// Run a solution part, print its result and return it in structured form.
+pub fn run_and_print<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    is_timed: bool,
+    format: OutputFormat,
+) -> PartResult {
+    let part_str = format!("Part {part}");
+
+    let (result, duration, samples, stats) = run_timed(func, input, is_timed, |result| {
+        if format == OutputFormat::Text {
+            print_result(result, &part_str, "");
+            if is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
+
+    let part_result = PartResult {
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        duration,
+        samples,
+        stats,
+    };
+    print_part_result(day, &part_result, format);
+    part_result
+}
+
+/// Run a solution part once without printing anything, e.g. on a worker thread.
+pub fn run_silently<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+) -> PartResult {
+    let (result, duration, samples, stats) = run_timed(func, input, false, |_| {});
+
+    PartResult {
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        duration,
+        samples,
+        stats,
+    }
+}
+
+/// Print the final result of a solution part.
+pub fn print_part_result(day: Day, result: &PartResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            print_result(
+                &result.answer,
+                &format!("Part {}", result.part),
+                &format_duration(&result.duration, result.samples),
+            );
+            if let Some(stats) = result.stats.filter(|_| env::args().any(|x| x == "--stats")) {
+                print_stats(&stats);
+            }
+        }
+        OutputFormat::Json => {
+            let status = if result.answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            };
+            print_part_json(day, result.part, Some(result), status);
+        }
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether we are benchmarking:
+///  1. by default, the function is executed once.
+///  2. when timed, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+fn run_timed<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    is_timed: bool,
+    hook: impl Fn(&T),
+) -> (T, Duration, u128, Option<BenchStats>) {
+    let timer = Instant::now();
+    let result = {
+        let input = input.clone();
+
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        func(input)
+    };
+    let base_time = timer.elapsed();
+
+    hook(&result);
+
+    if is_timed {
+        let (stats, samples) = bench(func, input, &base_time);
+        (result, stats.mean, samples, Some(stats))
+    } else {
+        (result, base_time, 1, None)
+    }
+}
+
+/// Bench a function. The first tenth of the iterations warms up caches and branch predictors and is not measured.
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (BenchStats, u128) {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    for _ in 0..bench_iterations / 10 {
+        black_box(func(black_box(input.clone())));
+    }
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..bench_iterations {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        let timer = Instant::now();
+        black_box(func(black_box(cloned)));
+        timers.push(timer.elapsed());
+    }
+
+    (BenchStats::from_samples(&timers), bench_iterations)
+}
+
+fn format_duration(duration: &Duration, samples: u128) -> String {
+    if samples == 1 {
+        format!(" ({duration:.1?})")
+    } else {
+        format!(" ({duration:.1?} @ {samples} samples)")
+    }
+}
+
+fn print_stats(stats: &BenchStats) {
+    println!(
+        "  {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · σ {:.1?} · {} outliers{ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.std_dev, stats.outliers
+    );
+}
+
+fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+    let is_intermediate_result = duration_str.is_empty();
+
+    match result {
+        Some(result) => {
+            if result.to_string().contains('\n') {
+                let str = format!("{part}: ▼ {duration_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                    println!("{result}");
+                }
+            } else {
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                }
+            }
+        }
+        None => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖             ");
+            }
+        }
+    }
+}
+
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+///  1. we are in `--release` mode.
+///  2. a session cookie is available.
+///  3. the answer ledger does not already know the answer to be wrong.
+///
+/// With `--format json`, its messages go to stderr so stdout only contains the JSON of the parts.
+fn submit_result<T: Display>(
+    result: T,
+    day: Day,
+    part: u8,
+    format: OutputFormat,
+) -> Option<SubmissionOutcome> {
+    let print = |message: &str| {
+        if format == OutputFormat::Json {
+            eprintln!("{message}");
+        } else {
+            println!("{message}");
+        }
+    };
+    let args: Vec<String> = env::args().collect();
+
+    if !args.contains(&"--submit".into()) {
+        return None;
+    }
+
+    if args.len() < 3 {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    }
+
+    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+
+    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    };
+
+    if part_submit != part {
+        return None;
+    }
+
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    let answer = result.to_string();
+    // a ledger that cannot be read could hide a rejected answer, and storing would overwrite it.
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read the answer ledger, not submitting: {e}");
+            process::exit(1);
+        }
+    };
+
+    match answers.check(day, part, &answer) {
+        AnswerCheck::Accepted => {
+            print(&format!(
+                "{answer} is already the accepted answer, not submitting."
+            ));
+            return None;
+        }
+        AnswerCheck::Solved(accepted) => {
+            eprintln!("This part was already solved with {accepted}, not submitting.");
+            process::exit(SubmissionOutcome::WrongLevel.exit_code());
+        }
+        AnswerCheck::Rejected(outcome) => {
+            eprintln!("{answer} was already submitted and rejected, not submitting.");
+            print(&outcome.to_string());
+            process::exit(outcome.exit_code());
+        }
+        AnswerCheck::AboveBound(bound) => {
+            eprintln!("Warning: {answer} is not below {bound}, which was too high.");
+        }
+        AnswerCheck::BelowBound(bound) => {
+            eprintln!("Warning: {answer} is not above {bound}, which was too low.");
+        }
+        AnswerCheck::Unknown => {}
+    }
+
+    print("Submitting result...");
+    match aoc_cli::submit(day, part, &answer) {
+        Ok(outcome) => {
+            print(&outcome.to_string());
+            answers.record(day, part, &answer, &outcome);
+            if answers.store_file().is_err() {
+                eprintln!("Failed to store the submission in the answer ledger.");
+            }
+            // exit early with a distinct status so wrapping scripts can react to the verdict.
+            if outcome != SubmissionOutcome::Correct {
+                process::exit(outcome.exit_code());
+            }
+            Some(outcome)
+        }
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        call_part, part_json, BenchStats, Context, OutputFormat, Param, PartResult, PartStatus,
+    };
+    use crate::day;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "ROW",
+        example: 10,
+        real: 2_000_000,
+    }];
+
+    #[test]
+    fn reads_params_for_the_input() {
+        assert_eq!(Context::example(PARAMS).param::<i32>("ROW"), 10);
+        assert_eq!(Context::real(PARAMS).param::<u64>("ROW"), 2_000_000);
+        let context = Context::example(PARAMS).with("ROW", 3).with("ROW", 4);
+        assert_eq!(context.param::<i64>("ROW"), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "not declared")]
+    fn rejects_undeclared_params() {
+        let _: i64 = Context::example(PARAMS).param("COLUMN");
+    }
+
+    #[test]
+    fn calls_parts_with_and_without_context() {
+        fn input_only(input: &str) -> Option<usize> {
+            Some(input.len())
+        }
+        fn with_context(_: &str, context: &Context) -> Option<bool> {
+            Some(context.is_example())
+        }
+        let context = Context::example(PARAMS);
+        assert_eq!(call_part(&input_only, "abc", &context), Some(3));
+        assert_eq!(call_part(&with_context, "abc", &context), Some(true));
+    }
+
+    #[test]
+    fn borrows_parsed_input_as_the_part_takes_it() {
+        fn sum(numbers: &[u32]) -> Option<u32> {
+            Some(numbers.iter().sum())
+        }
+        let parsed = vec![1, 2, 3];
+        assert_eq!(call_part(&sum, &parsed, &Context::real(PARAMS)), Some(6));
+    }
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_part_result() {
+        let result = PartResult {
+            part: 2,
+            answer: Some("42".into()),
+            duration: Duration::from_nanos(1_500),
+            samples: 100,
+            stats: None,
+        };
+        let json = part_json(day!(5), 2, Some(&result), PartStatus::Solved);
+        let object: &std::collections::HashMap<_, _> = json.get().unwrap();
+
+        assert_eq!(object["day"], JsonValue::Number(5.0));
+        assert_eq!(object["part"], JsonValue::Number(2.0));
+        assert_eq!(object["answer"], JsonValue::String("42".into()));
+        assert_eq!(object["duration_ns"], JsonValue::Number(1_500.0));
+        assert_eq!(object["samples"], JsonValue::Number(100.0));
+        assert_eq!(object["status"], JsonValue::String("solved".into()));
+    }
+
+    #[test]
+    fn serializes_skipped_part() {
+        let json = part_json(day!(5), 1, None, PartStatus::MissingInput);
+        let object: &std::collections::HashMap<_, _> = json.get().unwrap();
+
+        assert_eq!(object["answer"], JsonValue::Null);
+        assert_eq!(object["duration_ns"], JsonValue::Null);
+        assert_eq!(object["status"], JsonValue::String("missing_input".into()));
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let samples: Vec<Duration> = [10, 12, 11, 13, 10, 11, 12, 10, 11, 500]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+        let stats = BenchStats::from_samples(&samples);
+
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.p95, Duration::from_nanos(13));
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+        assert_eq!(stats.std_dev.as_nanos(), 1);
+    }
+
+    #[test]
+    fn keeps_uniform_samples() {
+        let stats = BenchStats::from_samples(&[Duration::from_nanos(7); 10]);
+
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.mean, Duration::from_nanos(7));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,739 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::runner::BenchStats;
+use crate::template::Day;
+
+/// Represents benchmark times for a single day.
+#[derive(Clone, Debug)]
+pub struct Timing {
+    pub day: Day,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Only present if the day parses its input once for both parts.
+    pub parse_nanos: Option<f64>,
+    pub parse_stats: Option<BenchStats>,
+    /// Includes the time spent parsing.
+    pub total_nanos: f64,
+}
+
+/// A benchmark result of a single part at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingRecord {
+    pub day: Day,
+    pub part: u8,
+    pub nanos: f64,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Represents benchmark times for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    pub data: Vec<Timing>,
+    /// Every stored benchmark run, oldest first.
+    pub history: Vec<TimingRecord>,
+}
+
+impl Timings {
+    /// Dehydrate timings to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(timings_file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file() -> Result<Self, String> {
+        Timings::read_from_path(&timings_file_path())
+    }
+
+    /// Rehydrate timings from a JSON file at `path`, e.g. of another year. If not present, returns empty timings.
+    /// Fails if the file exists but cannot be read or parsed, so storing never overwrites its history.
+    pub fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Timings::try_from(contents)
+                .map_err(|e| format!("{} is malformed: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display())),
+        }
+    }
+
+    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of `other` is appended to the history of `self`.
+    pub fn merge(&self, new: &Self) -> Self {
+        let mut data: Vec<Timing> = vec![];
+
+        for timing in &new.data {
+            data.push(timing.clone());
+        }
+
+        for timing in &self.data {
+            if !data.iter().any(|t| t.day == timing.day) {
+                data.push(timing.clone());
+            }
+        }
+
+        data.sort_unstable_by_key(|timing| timing.day);
+
+        let history = self.history.iter().chain(&new.history).cloned().collect();
+        Timings { data, history }
+    }
+
+    /// The most recent history record of a part.
+    pub fn last_record(&self, day: Day, part: u8) -> Option<&TimingRecord> {
+        self.history
+            .iter()
+            .filter(|r| r.day == day && r.part == part)
+            .max_by_key(|r| r.timestamp)
+    }
+
+    /// Sum up total duration of timings as millis.
+    pub fn total_millis(&self) -> f64 {
+        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+    }
+
+    /// Whether every part of a day of `year` is benched. The last day of an event is complete after part one.
+    pub fn is_day_complete(&self, day: Day, year: u32) -> bool {
+        let needs_part_2 = day.parts(year) == 2;
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1_nanos.is_some() && (t.part_2_nanos.is_some() || !needs_part_2)
+        })
+    }
+}
+
+fn timings_file_path() -> PathBuf {
+    crate::template::year_directory().join("data").join("timings.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Timings> for JsonValue {
+    fn from(value: Timings) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Timings {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        // the history is absent in timings stored by older versions.
+        let json_history = match json.get("history") {
+            None => &vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?,
+        };
+
+        Ok(Timings {
+            data: json_data
+                .iter()
+                .map(Timing::try_from)
+                .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(TimingRecord::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+
+        map.insert(
+            "part_1_nanos".into(),
+            value
+                .part_1_nanos
+                .map_or(JsonValue::Null, JsonValue::Number),
+        );
+        map.insert(
+            "part_2_nanos".into(),
+            value
+                .part_2_nanos
+                .map_or(JsonValue::Null, JsonValue::Number),
+        );
+
+        map.insert(
+            "parse_nanos".into(),
+            value.parse_nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value.part_1_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "part_2_stats".into(),
+            value.part_2_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "parse_stats".into(),
+            value.parse_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timing {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected timing.day to be a Day struct.")?;
+
+        let part_nanos = |part: u8| match json
+            .get(&format!("part_{part}_nanos"))
+            // timings stored by older versions contain formatted strings such as "74.13ns".
+            .or_else(|| json.get(&format!("part_{part}")))
+        {
+            Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::Number(nanos)) => Ok(Some(*nanos)),
+            Some(JsonValue::String(duration)) => parse_duration_nanos(duration).map(Some).ok_or(
+                format!("Could not parse timing.part_{part} \"{duration}\"."),
+            ),
+            _ => Err(format!(
+                "Expected timing.part_{part}_nanos to be null or a number."
+            )),
+        };
+
+        let total_nanos = json
+            .get("total_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected timing.total_nanos to be a number.")?;
+
+        let parse_nanos = match json.get("parse_nanos") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::Number(nanos)) => Some(*nanos),
+            _ => return Err("Expected timing.parse_nanos to be null or a number.".into()),
+        };
+
+        // stats and parse times are absent in timings stored by older versions.
+        let stats = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => BenchStats::try_from(v).map(Some),
+        };
+
+        Ok(Timing {
+            day,
+            part_1_nanos: part_nanos(1)?,
+            part_2_nanos: part_nanos(2)?,
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            parse_nanos,
+            parse_stats: stats("parse_stats")?,
+            total_nanos,
+        })
+    }
+}
+
+/// Parse a duration formatted by `Duration`'s `Debug` implementation, e.g. "74.13ns" or "1.2ms".
+fn parse_duration_nanos(s: &str) -> Option<f64> {
+    let (value, factor) = [
+        ("ns", 1e0),
+        ("µs", 1e3),
+        ("us", 1e3),
+        ("ms", 1e6),
+        ("s", 1e9),
+    ]
+    .into_iter()
+    .find_map(|(unit, factor)| Some((s.strip_suffix(unit)?, factor)))?;
+    value.parse::<f64>().ok().map(|value| value * factor)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&TimingRecord> for JsonValue {
+    fn from(value: &TimingRecord) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let timestamp = JsonValue::Number(value.timestamp as f64);
+
+        JsonValue::Object(HashMap::from([
+            ("day".into(), JsonValue::String(value.day.to_string())),
+            ("part".into(), JsonValue::Number(value.part.into())),
+            ("nanos".into(), JsonValue::Number(value.nanos)),
+            ("timestamp".into(), timestamp),
+        ]))
+    }
+}
+
+impl TryFrom<&JsonValue> for TimingRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected record.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(TimingRecord {
+            day,
+            part: number("part")? as u8,
+            nanos: number("nanos")?,
+            timestamp: number("timestamp")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<BenchStats> for JsonValue {
+    fn from(value: BenchStats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+        #[allow(clippy::cast_precision_loss)]
+        let outliers = JsonValue::Number(value.outliers as f64);
+
+        JsonValue::Object(HashMap::from([
+            ("mean_nanos".into(), nanos(value.mean)),
+            ("min_nanos".into(), nanos(value.min)),
+            ("median_nanos".into(), nanos(value.median)),
+            ("p95_nanos".into(), nanos(value.p95)),
+            ("std_dev_nanos".into(), nanos(value.std_dev)),
+            ("outliers".into(), outliers),
+        ]))
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nanos = |key: &str| number(key).map(|n| Duration::from_nanos(n as u64));
+
+        Ok(BenchStats {
+            mean: nanos("mean_nanos")?,
+            min: nanos("min_nanos")?,
+            median: nanos("median_nanos")?,
+            p95: nanos("p95_nanos")?,
+            std_dev: nanos("std_dev_nanos")?,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Timing, Timings};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1_nanos: Some(10_000_000_f64),
+                    part_2_nanos: Some(20_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 3e+10,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1_nanos: Some(30_000_000_f64),
+                    part_2_nanos: Some(40_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 7e+10,
+                },
+                Timing {
+                    day: day!(4),
+                    part_1_nanos: Some(40_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 4e+10,
+                },
+            ],
+            history: vec![],
+        }
+    }
+
+    mod deserialization {
+        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn handles_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 1000000, "part_2_nanos": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_1_nanos, Some(1_000_000_f64));
+            assert_eq!(timing.part_2_nanos, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_formatted_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.5ms", "total_nanos": 1500074.13 }, { "day": "02", "part_1": "2.0µs", "part_2": null, "total_nanos": 2000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_1_nanos, Some(74.13));
+            assert_eq!(timings.data[0].part_2_nanos, Some(1_500_000_f64));
+            assert_eq!(timings.data[1].part_1_nanos, Some(2_000_f64));
+            assert_eq!(timings.data[1].part_2_nanos, None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unparseable_formatted_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 1000, "part_2_nanos": null, "total_nanos": 1000, "part_1_stats": { "mean_nanos": 1000, "min_nanos": 900, "median_nanos": 990, "p95_nanos": 1200, "std_dev_nanos": 50, "outliers": 3 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.min, Duration::from_nanos(900));
+            assert_eq!(stats.p95, Duration::from_nanos(1200));
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timing.part_2_stats, None);
+            assert_eq!(timing.parse_nanos, None);
+        }
+
+        #[test]
+        fn handles_json_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 1000, "part_2_nanos": 2000, "parse_nanos": 500, "total_nanos": 3500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse_nanos, Some(500_f64));
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse_nanos, Some(500_f64));
+        }
+
+        #[test]
+        fn handles_json_history() {
+            let json = r#"{ "data": [], "history": [{ "day": "03", "part": 2, "nanos": 1500, "timestamp": 1733000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 1);
+            let record = timings.history.first().unwrap();
+            assert_eq!(record.day, day!(3));
+            assert_eq!(record.part, 2);
+            assert_eq!(record.nanos, 1500_f64);
+            assert_eq!(record.timestamp, 1_733_000_000);
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let json = r#"{ "data": [] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_json() {
+            let json = r#"{}"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_timings() {
+            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn fails_for_damaged_files() {
+            let dir = std::env::temp_dir().join(format!("aoc-timings-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("timings.json");
+
+            assert_eq!(Timings::read_from_path(&path).unwrap().data.len(), 0);
+            std::fs::write(&path, "{ \"data\": [").unwrap();
+            assert!(Timings::read_from_path(&path).is_err());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_timings;
+        use std::collections::HashMap;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn serializes_timings() {
+            let timings = get_mock_timings();
+            let value = JsonValue::try_from(timings).unwrap();
+            assert_eq!(
+                value
+                    .get::<HashMap<String, JsonValue>>()
+                    .unwrap()
+                    .get("data")
+                    .unwrap()
+                    .get::<Vec<JsonValue>>()
+                    .unwrap()
+                    .len(),
+                3
+            );
+        }
+    }
+
+    mod is_day_complete {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        #[test]
+        fn handles_completed_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: Some(2_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 3_000_000_000_f64,
+                }],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1), 2024), true);
+        }
+
+        #[test]
+        fn handles_partial_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1), 2024), false);
+        }
+
+        #[test]
+        fn handles_last_day_after_part_one() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(25),
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(25), 2024), true);
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 0.0,
+                }],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1), 2024), false);
+        }
+    }
+
+    mod merge {
+        use crate::{
+            day,
+            template::timings::{Timing, TimingRecord, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn handles_disjunct_timings() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(3),
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 0_f64,
+                }],
+                history: vec![],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[3].day, day!(4));
+        }
+
+        #[test]
+        fn handles_overlapping_timings() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_nanos: None,
+                    parse_stats: None,
+                    total_nanos: 0_f64,
+                }],
+                history: vec![],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[2].day, day!(4));
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let timings = Timings::default();
+            let other = get_mock_timings();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+
+        #[test]
+        fn handles_empty_other_timings() {
+            let timings = get_mock_timings();
+            let other = Timings::default();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+
+        #[test]
+        fn appends_history() {
+            let record = |nanos: f64, timestamp: u64| TimingRecord {
+                day: day!(1),
+                part: 1,
+                nanos,
+                timestamp,
+            };
+            let timings = Timings {
+                data: vec![],
+                history: vec![record(10.0, 1), record(20.0, 2)],
+            };
+            let other = Timings {
+                data: vec![],
+                history: vec![record(30.0, 3)],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.history.len(), 3);
+            assert_eq!(merged.last_record(day!(1), 1), Some(&record(30.0, 3)));
+            assert_eq!(merged.last_record(day!(1), 2), None);
+        }
+    }
+}
@@ -12,10 +12,12 @@ use super::{
     grid::{Grid, GridCell, GridPos},
 };
 
-struct GraphSearchData<T> {
+type EdgePredicate<'a, T> = &'a dyn Fn((&GridPos, &T), (&GridPos, &T)) -> bool;
+
+struct GraphSearchData<'a, T> {
     start: GridPos,
-    has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-    matches: fn(&GridPos, &T) -> bool,
+    has_edge: EdgePredicate<'a, T>,
+    matches: &'a dyn Fn(&GridPos, &T) -> bool,
     only_first: bool,
     continue_on_match: bool,
 }
@@ -26,13 +28,13 @@ impl<T: GridCell> Grid<T> {
     pub fn bfs_first_match(
         &self,
         start: GridPos,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        matches: fn(&GridPos, &T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        matches: impl Fn(&GridPos, &T) -> bool,
     ) -> Option<(usize, GridPos)> {
         let searcher = GraphSearchData {
             start,
-            has_edge,
-            matches,
+            has_edge: &has_edge,
+            matches: &matches,
             only_first: true,
             continue_on_match: false,
         };
@@ -42,13 +44,13 @@ impl<T: GridCell> Grid<T> {
     pub fn bfs_all_matches(
         &self,
         start: GridPos,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        matches: fn(&GridPos, &T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        matches: impl Fn(&GridPos, &T) -> bool,
     ) -> Vec<(usize, GridPos)> {
         let searcher = GraphSearchData {
             start,
-            has_edge,
-            matches,
+            has_edge: &has_edge,
+            matches: &matches,
             only_first: false,
             continue_on_match: false,
         };
@@ -58,13 +60,13 @@ impl<T: GridCell> Grid<T> {
     pub fn dfs_first_match(
         &self,
         start: GridPos,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        matches: fn(&GridPos, &T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        matches: impl Fn(&GridPos, &T) -> bool,
     ) -> Option<(usize, GridPos)> {
         let searcher = GraphSearchData {
             start,
-            has_edge,
-            matches,
+            has_edge: &has_edge,
+            matches: &matches,
             only_first: true,
             continue_on_match: false,
         };
@@ -74,13 +76,13 @@ impl<T: GridCell> Grid<T> {
     pub fn dfs_all_matches(
         &self,
         start: GridPos,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        matches: fn(&GridPos, &T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        matches: impl Fn(&GridPos, &T) -> bool,
     ) -> Vec<(usize, GridPos)> {
         let searcher = GraphSearchData {
             start,
-            has_edge,
-            matches,
+            has_edge: &has_edge,
+            matches: &matches,
             only_first: false,
             continue_on_match: false,
         };
@@ -90,12 +92,12 @@ impl<T: GridCell> Grid<T> {
     pub fn bfs_dfs_full(
         &self,
         start: GridPos,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
     ) -> HashMap<GridPos, (usize, &T)> {
         let searcher = GraphSearchData {
             start,
-            has_edge,
-            matches: |_, _| true,
+            has_edge: &has_edge,
+            matches: &|_, _| true,
             only_first: false,
             continue_on_match: true,
         };
@@ -110,7 +112,7 @@ impl<T: GridCell> Grid<T> {
 
     /* Helper Methods For BFS/DFS */
 
-    fn bfs(&self, searcher: &GraphSearchData<T>) -> Vec<(usize, GridPos)> {
+    fn bfs(&self, searcher: &GraphSearchData<'_, T>) -> Vec<(usize, GridPos)> {
         self.bfs_dfs_internal(
             searcher,
             VecDeque::new(),
@@ -119,13 +121,13 @@ impl<T: GridCell> Grid<T> {
         )
     }
 
-    fn dfs(&self, searcher: &GraphSearchData<T>) -> Vec<(usize, GridPos)> {
-        self.bfs_dfs_internal(&searcher, Vec::new(), |s, i| s.push(i), |s| s.pop())
+    fn dfs(&self, searcher: &GraphSearchData<'_, T>) -> Vec<(usize, GridPos)> {
+        self.bfs_dfs_internal(searcher, Vec::new(), |s, i| s.push(i), |s| s.pop())
     }
 
     fn bfs_dfs_internal<Storage: Clone>(
         &self,
-        gsdata: &GraphSearchData<T>,
+        gsdata: &GraphSearchData<'_, T>,
         queue_stack: Storage,
         qs_insert: fn(&mut Storage, (usize, GridPos)),
         qs_remove: fn(&mut Storage) -> Option<(usize, GridPos)>,
@@ -194,7 +196,7 @@ impl<T: GridCell> Grid<T> {
             split_by_row
                 .get_mut(row)
                 .unwrap()
-                .sort_by(|(_, p1, _), (_, p2, _)| p1.cmp(p2));
+                .sort_by_key(|(_, pos, _)| *pos);
         }
         let new_grid: Vec<Vec<(usize, &T)>> = split_by_row
             .iter()
@@ -216,7 +218,7 @@ impl<T: GridCell> Grid<T> {
     pub fn dijkstra(
         &self,
         start: GridPos,
-        edge_weight: fn(&GridPos, &GridPos) -> Option<i64>,
+        edge_weight: impl Fn(&GridPos, &GridPos) -> Option<i64>,
     ) -> HashMap<GridPos, (i64, Vec<GridPos>)> {
         let mut dists_paths = HashMap::with_capacity(self.rows() * self.cols());
         let mut heap = BinaryHeap::new();
@@ -255,18 +257,18 @@ impl<T: GridCell> Grid<T> {
 
     pub fn to_undir_graph<EWeight>(
         &self,
-        is_node: fn(GridPos, T) -> bool,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        edge_weight: fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
+        is_node: impl Fn(GridPos, T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        edge_weight: impl Fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
     ) -> GraphWrapper<GridPos, EWeight, Undirected> {
         self.to_graph_internal::<EWeight, Undirected>(false, is_node, has_edge, edge_weight)
     }
 
     pub fn to_dir_graph<EWeight>(
         &self,
-        is_node: fn(GridPos, T) -> bool,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        edge_weight: fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
+        is_node: impl Fn(GridPos, T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        edge_weight: impl Fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
     ) -> GraphWrapper<GridPos, EWeight, Directed> {
         self.to_graph_internal::<EWeight, Directed>(true, is_node, has_edge, edge_weight)
     }
@@ -274,9 +276,9 @@ impl<T: GridCell> Grid<T> {
     fn to_graph_internal<EWeight, EType: EdgeType>(
         &self,
         directed: bool,
-        is_node: fn(GridPos, T) -> bool,
-        has_edge: fn((&GridPos, &T), (&GridPos, &T)) -> bool,
-        edge_weight: fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
+        is_node: impl Fn(GridPos, T) -> bool,
+        has_edge: impl Fn((&GridPos, &T), (&GridPos, &T)) -> bool,
+        edge_weight: impl Fn((&GridPos, &T), (&GridPos, &T)) -> EWeight,
     ) -> GraphWrapper<GridPos, EWeight, EType> {
         let num_cells = self.rows() * self.cols();
         let mut pos_to_node_id = HashMap::with_capacity(num_cells);
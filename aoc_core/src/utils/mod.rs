@@ -0,0 +1,6 @@
+pub mod direction;
+pub mod grid;
+pub mod parse;
+pub mod show;
+pub mod graph_algos;
+pub mod grid_graph;
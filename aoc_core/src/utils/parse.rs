@@ -59,7 +59,7 @@ pub fn lines_to_columns(input: &str) -> Vec<String> {
     let mut columns = vec![];
     for line in input.lines() {
         let line = line.as_bytes();
-        if columns.len() == 0 {
+        if columns.is_empty() {
             for _ in 0..line.len() {
                 columns.push(vec![]);
             }
@@ -1,3 +1,3 @@
-pub mod template;
+pub use aoc_core::template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,6 +1,10 @@
 use advent_of_code::template::commands::{
-    all, attempt, download, new_year, read, scaffold, set_year, solve, time,
+    all, attempt, download, examples, list_years, new_year, read, remove_year, scaffold, set_year,
+    solve, sync_utils, time, verify,
 };
+use advent_of_code::template::run_multi::child_commands;
+use advent_of_code::template::runner::OutputFormat;
+use advent_of_code::template::{set_year_override, workspace_years, ANSI_BOLD, ANSI_RESET};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -9,7 +13,7 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{runner::OutputFormat, Day};
     use std::process;
 
     pub enum AppArguments {
@@ -19,16 +23,21 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: OutputFormat,
         },
         Try {
             day: Day,
@@ -37,11 +46,24 @@ mod args {
         },
         All {
             release: bool,
+            all_years: bool,
+            jobs: Option<usize>,
+            timeout: Option<u64>,
+            format: OutputFormat,
+        },
+        Verify {
+            release: bool,
+            timeout: Option<u64>,
         },
         Time {
             all: bool,
+            all_years: bool,
             day: Option<Day>,
             store: bool,
+            stats: bool,
+            threshold: Option<f64>,
+            timeout: Option<u64>,
+            format: OutputFormat,
         },
         NewYear {
             year: u32,
@@ -49,26 +71,57 @@ mod args {
         SetYear {
             year: u32,
         },
+        RemoveYear {
+            year: u32,
+            yes: bool,
+        },
+        ListYears,
+        SyncUtils {
+            from: Option<u32>,
+            modules: Vec<String>,
+        },
         GetYear,
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command line. The second value is the year passed with `--year`, which applies to every command.
+    pub fn parse() -> Result<(AppArguments, Option<u32>), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = args.opt_value_from_str("--year")?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                all_years: args.contains("--all-years"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            },
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                timeout: args.opt_value_from_str("--timeout")?,
             },
             Some("time") => {
                 let all = args.contains("--all");
+                let all_years = args.contains("--all-years");
                 let store = args.contains("--store");
+                let stats = args.contains("--stats");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let timeout = args.opt_value_from_str("--timeout")?;
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
 
                 AppArguments::Time {
                     all,
+                    all_years,
                     day: args.opt_free_from_str()?,
                     store,
+                    stats,
+                    threshold,
+                    timeout,
+                    format,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -77,16 +130,21 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
             },
             Some("try") => AppArguments::Try {
                 day: args.free_from_str()?,
@@ -100,6 +158,19 @@ mod args {
                 year: args.free_from_str()?,
             },
             Some("get-year") => AppArguments::GetYear,
+            Some("remove-year") => AppArguments::RemoveYear {
+                yes: args.contains("--yes"),
+                year: args.free_from_str()?,
+            },
+            Some("list-years") => AppArguments::ListYears,
+            Some("sync-utils") => {
+                let from = args.opt_value_from_str("--from")?;
+                let mut modules = vec![];
+                while let Some(module) = args.opt_free_from_str()? {
+                    modules.push(module);
+                }
+                AppArguments::SyncUtils { from, modules }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -117,7 +188,39 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((app_args, year))
+    }
+}
+
+/// Run a year's `solutions` binary with `args` for every year of the workspace.
+/// Exits with a non-zero status afterwards if any of the years failed.
+fn run_all_years(args: &[String], is_release: bool, format: OutputFormat) {
+    let mut failed = vec![];
+
+    for year in workspace_years() {
+        let header = format!("{ANSI_BOLD}{year}{ANSI_RESET}\n====");
+        // keep stdout parseable in JSON mode.
+        if format == OutputFormat::Json {
+            eprintln!("{header}");
+        } else {
+            println!("{header}");
+        }
+
+        set_year_override(year);
+        match child_commands::run_solutions(args, is_release) {
+            Ok(status) if status.success() => {}
+            Ok(_) => failed.push(year),
+            Err(e) => {
+                eprintln!("Failed to run the solutions binary: {e:?}");
+                failed.push(year);
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        let failed: Vec<String> = failed.iter().map(u32::to_string).collect();
+        eprintln!("Failed years: {}", failed.join(", "));
+        std::process::exit(1);
     }
 }
 
@@ -127,51 +230,109 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+        Ok((args, year)) => {
+            if let Some(year) = year {
+                set_year_override(year);
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            AppArguments::Try { day, test, dhat } => attempt::handle(day, test, dhat),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+            run(args);
+        }
+    };
+}
+
+fn run(args: AppArguments) {
+    match args {
+        AppArguments::All {
+            release,
+            all_years: true,
+            jobs,
+            timeout,
+            format,
+        } => run_all_years(&all::args(jobs, timeout, format), release, format),
+        AppArguments::All {
+            release,
+            all_years: false,
+            jobs,
+            timeout,
+            format,
+        } => all::handle(release, jobs, timeout, format),
+        AppArguments::Verify { release, timeout } => verify::handle(release, timeout),
+        AppArguments::Time {
+            day,
+            all,
+            all_years: true,
+            store,
+            stats,
+            threshold,
+            timeout,
+            format,
+        } => {
+            let args = time::args(day, all, store, stats, threshold, timeout, format);
+            run_all_years(&args, true, format);
+        }
+        AppArguments::Time {
+            day,
+            all,
+            all_years: false,
+            store,
+            stats,
+            threshold,
+            timeout,
+            format,
+        } => time::handle(day, all, store, stats, threshold, timeout, format),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Read { day } => read::handle(day),
+        AppArguments::Examples { day } => examples::handle(day),
+        AppArguments::Scaffold {
+            day,
+            download,
+            overwrite,
+            template,
+        } => {
+            if download {
+                download::handle(day);
             }
-            AppArguments::NewYear { year } => new_year::handle(year),
-            AppArguments::SetYear { year } => set_year::handle(year),
-            AppArguments::GetYear => {
-                let year = advent_of_code::template::get_year_exit_on_fail();
-                println!("The repository is currently set to {}", year);
+            scaffold::handle(day, overwrite, template.as_deref());
+            if download {
+                examples::handle(day);
             }
-        },
-    };
+        }
+        AppArguments::Solve {
+            day,
+            release,
+            dhat,
+            submit,
+            format,
+        } => solve::handle(day, release, dhat, submit, format),
+        AppArguments::Try { day, test, dhat } => attempt::handle(day, test, dhat),
+        #[cfg(feature = "today")]
+        AppArguments::Today => {
+            match Day::today() {
+                Some(day) => {
+                    download::handle(day);
+                    scaffold::handle(day, false, None);
+                    read::handle(day)
+                }
+                None => {
+                    eprintln!(
+                        "`today` command can only be run on the days of \
+                            this december's event. Please use `scaffold` with a specific day."
+                    );
+                    process::exit(1)
+                }
+            };
+        }
+        AppArguments::NewYear { year } => new_year::handle(year),
+        AppArguments::SetYear { year } => set_year::handle(year),
+        AppArguments::RemoveYear { year, yes } => remove_year::handle(year, yes),
+        AppArguments::ListYears => list_years::handle(),
+        AppArguments::SyncUtils { from, modules } => sync_utils::handle(from, &modules),
+        AppArguments::GetYear => {
+            // the year in the config, not one passed with `--year`.
+            let Some(year) = set_year::configured_year() else {
+                eprintln!("Failed to get the currently set AOC year");
+                std::process::exit(1);
+            };
+            println!("The repository is currently set to {}", year);
+        }
+    }
 }